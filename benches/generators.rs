@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use freqiterator::{FreqGenerator, A0};
+use num_traits::Pow;
+
+const NOTES: usize = 4096;
+
+fn freq_generator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FreqGenerator");
+    group.bench_function("cached ratio", |b| {
+        b.iter(|| {
+            FreqGenerator::new(black_box(A0), black_box(12f32))
+                .take(NOTES)
+                .fold(0f32, |acc, f| acc + f)
+        });
+    });
+    group.bench_function("pow every step", |b| {
+        b.iter(|| {
+            let scale = black_box(12f32);
+            let mut freq = black_box(A0);
+            (0..NOTES).fold(0f32, |acc, _| {
+                freq *= 2f32.pow(1f32 / black_box(scale));
+                acc + freq
+            })
+        });
+    });
+    group.finish();
+}
+
+fn fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill");
+    let mut buf = [0f32; NOTES];
+    group.bench_function("scalar", |b| {
+        b.iter(|| FreqGenerator::new(black_box(A0), black_box(12f32)).fill(&mut buf));
+    });
+    group.bench_function("simd", |b| {
+        b.iter(|| FreqGenerator::new(black_box(A0), black_box(12f32)).fill_simd(&mut buf));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, freq_generator, fill);
+criterion_main!(benches);
@@ -0,0 +1,95 @@
+//! Approximate comparisons, behind the `approx` feature. Frequencies are compared as plain `f32`s,
+//! so tolerances are in hertz; see [`assert_freq_eq!`](crate::assert_freq_eq) for a tolerance in cents.
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{Hz, Note};
+
+impl AbsDiffEq for Hz {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for Hz {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Hz {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+/// Notes are compared by frequency, like their [`PartialEq`] implementation.
+impl AbsDiffEq for Note {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.freq.abs_diff_eq(&other.freq, epsilon)
+    }
+}
+
+/// Notes are compared by frequency, like their [`PartialEq`] implementation.
+impl RelativeEq for Note {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.freq.relative_eq(&other.freq, epsilon, max_relative)
+    }
+}
+
+/// Notes are compared by frequency, like their [`PartialEq`] implementation.
+impl UlpsEq for Note {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.freq.ulps_eq(&other.freq, epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+
+    use crate::{Hz, Note};
+
+    #[test]
+    fn hz() {
+        assert_abs_diff_eq!(Hz(440.), Hz(440.0001), epsilon = 1e-3);
+        assert_abs_diff_ne!(Hz(440.), Hz(440.01), epsilon = 1e-3);
+        assert_relative_eq!(Hz(440.), Hz(440.0001), max_relative = 1e-6);
+    }
+
+    #[test]
+    fn note() {
+        assert_abs_diff_eq!(
+            Note::from_freq(440.),
+            Note::from_freq(440.0001),
+            epsilon = 1e-3
+        );
+    }
+}
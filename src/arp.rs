@@ -0,0 +1,128 @@
+//! Arpeggios of chords.
+use core::{cmp::Ordering, iter::FusedIterator};
+
+/// Order in which an [`Arpeggiator`] plays the notes of its chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArpPattern {
+    /// From the lowest note to the highest, then again from the lowest.
+    Up,
+    /// From the highest note to the lowest, then again from the highest.
+    Down,
+    /// Up then down, without repeating the lowest and highest notes: root, third, fifth, third, root, third...
+    UpDown,
+    /// Random notes, from a pseudo-random generator seeded with the given value so the sequence is reproducible.
+    Random(u64),
+    /// The notes in the order of the chord array, whatever their pitch.
+    AsPlayed,
+}
+
+/// Arpeggio generator. Acts as an iterator yielding the notes of a chord one at a time in the order of an [`ArpPattern`],
+/// looping forever. Chords come from [`ScaleGenerator::triad`](crate::ScaleGenerator::triad) or
+/// [`ChordGenerator`](crate::ChordGenerator) for example.
+#[derive(Debug, Clone)]
+pub struct Arpeggiator<const N: usize, T = f32> {
+    /// Notes of the chord, sorted from low to high unless played as is.
+    chord: [T; N],
+    pattern: ArpPattern,
+    /// Number of notes yielded so far, or state of the pseudo-random generator.
+    step: u64,
+}
+
+impl<const N: usize, T> Arpeggiator<N, T>
+where
+    T: PartialOrd,
+{
+    /// Make a new arpeggiator over `chord`. Notes that can't be compared (NaN) land anywhere in the sorted patterns.
+    pub fn new(mut chord: [T; N], pattern: ArpPattern) -> Self {
+        if !matches!(pattern, ArpPattern::AsPlayed | ArpPattern::Random(_)) {
+            chord.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        }
+        let step = match pattern {
+            ArpPattern::Random(seed) => seed,
+            _ => 0,
+        };
+        Self {
+            chord,
+            pattern,
+            step,
+        }
+    }
+}
+
+/// Ends right away for an empty chord, and never otherwise.
+impl<const N: usize, T> Iterator for Arpeggiator<N, T>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = N as u64;
+        if len == 0 {
+            return None;
+        }
+        let index = match self.pattern {
+            ArpPattern::Up | ArpPattern::AsPlayed => self.step % len,
+            ArpPattern::Down => len - 1 - self.step % len,
+            ArpPattern::UpDown => {
+                let turn = (2 * len - 2).max(1);
+                let position = self.step % turn;
+                position.min(turn - position)
+            }
+            ArpPattern::Random(_) => {
+                // Knuth's MMIX linear congruential generator, keeping the better high bits
+                self.step = self
+                    .step
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                (self.step >> 33) % len
+            }
+        };
+        if !matches!(self.pattern, ArpPattern::Random(_)) {
+            self.step = self.step.wrapping_add(1);
+        }
+        // index is below N
+        self.chord.get(index as usize).copied()
+    }
+}
+
+impl<const N: usize, T> FusedIterator for Arpeggiator<N, T> where Self: Iterator {}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{ArpPattern, Arpeggiator};
+    use crate::{Key, Mode, ScaleGenerator};
+
+    #[test]
+    fn up() {
+        let c_major = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::IONIAN);
+        let [root, third, fifth] = c_major.triad(0);
+        let mut arp = Arpeggiator::new([fifth, root, third], ArpPattern::Up);
+        for _ in 0..3 {
+            assert_eq!(
+                [Some(root), Some(third), Some(fifth)],
+                [arp.next(), arp.next(), arp.next()]
+            );
+        }
+    }
+
+    #[test]
+    fn patterns() {
+        let chord = [1, 3, 2, 4];
+        let take = |pattern| -> [u8; 8] {
+            let mut arp = Arpeggiator::new(chord, pattern);
+            core::array::from_fn(|_| arp.next().unwrap())
+        };
+        assert_eq!([4, 3, 2, 1, 4, 3, 2, 1], take(ArpPattern::Down));
+        assert_eq!([1, 2, 3, 4, 3, 2, 1, 2], take(ArpPattern::UpDown));
+        assert_eq!([1, 3, 2, 4, 1, 3, 2, 4], take(ArpPattern::AsPlayed));
+        let random = take(ArpPattern::Random(42));
+        assert_eq!(random, take(ArpPattern::Random(42)));
+        assert_ne!(random, take(ArpPattern::Random(43)));
+        assert!(random.iter().all(|note| chord.contains(note)));
+        assert_eq!(Some(7), Arpeggiator::new([7], ArpPattern::UpDown).nth(5));
+        assert_eq!(None, Arpeggiator::<0, f32>::new([], ArpPattern::Up).next());
+    }
+}
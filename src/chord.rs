@@ -0,0 +1,253 @@
+//! Chords stacked within a scale.
+use core::{
+    cmp::Ordering,
+    iter::FusedIterator,
+    ops::{Div, MulAssign},
+};
+
+use num_traits::{cast, Float, FromPrimitive, Pow};
+
+use crate::ScaleGenerator;
+
+/// Chord generator. Acts as an iterator yielding `N` notes at once, built from a degree pattern
+/// on successive degrees of a [`ScaleGenerator`]: `[0, 2, 4]` yields the triads of the scale
+/// on its 1st, then 2nd, then 3rd degree...
+#[derive(Debug, Clone)]
+pub struct ChordGenerator<const N: usize, T = f32>
+where
+    T: Float + Pow<T, Output = T> + From<u8> + MulAssign + FromPrimitive,
+{
+    scale: ScaleGenerator<T>,
+    degrees: [usize; N],
+}
+
+impl<const N: usize, T> ChordGenerator<N, T>
+where
+    T: Float + Pow<T, Output = T> + From<u8> + MulAssign + FromPrimitive,
+{
+    /// Make a new generator stacking `degrees` (counted from each chord's root) within `scale`.
+    /// The first chord is rooted on the current note of `scale`, its tonic if it hasn't been advanced.
+    pub fn new(scale: ScaleGenerator<T>, degrees: [usize; N]) -> Self {
+        Self { scale, degrees }
+    }
+}
+
+impl<const N: usize, T> Iterator for ChordGenerator<N, T>
+where
+    T: Float + Pow<T, Output = T> + From<u8> + MulAssign + FromPrimitive,
+{
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chord = self.degrees.map(|degree| self.scale.degree(degree));
+        self.scale.next()?;
+        Some(chord)
+    }
+}
+
+impl<const N: usize, T> FusedIterator for ChordGenerator<N, T>
+where
+    Self: Iterator,
+    T: Float + Pow<T, Output = T> + From<u8> + MulAssign + FromPrimitive,
+{
+}
+
+/// Turn the close-position chord `chord` (sorted from low to high) into its `n`th inversion: its lowest note goes up
+/// an octave `n` times, keeping the chord sorted. On a triad, 1 gives the first inversion (the third in the bass)
+/// and 2 the second one (the fifth in the bass). Inversions past the chord size wrap around an octave higher,
+/// so inverting a triad 3 times moves it up an octave in root position.
+pub fn invert<T>(chord: &mut [T], n: usize)
+where
+    T: Float,
+{
+    if chord.is_empty() {
+        return;
+    }
+    let octaves = cast::<_, T>(n / chord.len()).unwrap_or_else(T::nan);
+    let octave = cast::<_, T>(2).unwrap_or_else(T::nan);
+    for note in chord.iter_mut() {
+        *note = *note * octave.powf(octaves);
+    }
+    for _ in 0..n % chord.len() {
+        chord[0] = chord[0] * octave;
+        chord.rotate_left(1);
+    }
+}
+
+/// Drop-2 voicing of the close-position chord `chord` (sorted from low to high): the second note from the top goes
+/// down an octave, so a C major 7th C E G B becomes G C E B. The result is sorted from low to high.
+/// Chords of less than 2 notes are left as they are.
+pub fn drop2<const N: usize, T>(mut chord: [T; N]) -> [T; N]
+where
+    T: Float,
+{
+    if let Some(voice) = N.checked_sub(2) {
+        chord[voice] = chord[voice] / cast(2).unwrap_or_else(T::nan);
+        sort(&mut chord);
+    }
+    chord
+}
+
+/// Open voicing of the close-position chord `chord` (sorted from low to high): every other note from the second one
+/// goes up `octaves` octaves, so with 1 a C major triad C E G becomes C G E, the E an octave higher.
+/// The result is sorted from low to high.
+pub fn spread<const N: usize, T>(mut chord: [T; N], octaves: u32) -> [T; N]
+where
+    T: Float,
+{
+    let ratio = cast::<_, T>(2)
+        .unwrap_or_else(T::nan)
+        .powf(cast(octaves).unwrap_or_else(T::nan));
+    for note in chord.iter_mut().skip(1).step_by(2) {
+        *note = *note * ratio;
+    }
+    sort(&mut chord);
+    chord
+}
+
+/// Sort notes from low to high.
+fn sort<T: Float>(chord: &mut [T]) {
+    chord.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+}
+
+/// Overlapping clusters of `N` successive notes, see [`ScaleGenerator::windows`].
+#[derive(Debug, Clone)]
+pub struct Windows<const N: usize, I>
+where
+    I: Iterator,
+{
+    iter: I,
+    /// Last window yielded, `None` until the first one.
+    window: Option<[I::Item; N]>,
+}
+
+impl<T, I> ScaleGenerator<T, I>
+where
+    I: Iterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    /// Yield every run of `N` successive notes, like [`slice::windows`] over the infinite scale:
+    /// notes 1 and 2, then 2 and 3... for `N = 2`. Each window keeps the last `N - 1` notes of the previous one,
+    /// so comparing the two spots parallel motion.
+    ///
+    /// # Panics
+    /// If `N` is 0.
+    pub fn windows<const N: usize>(self) -> Windows<N, Self> {
+        assert!(N != 0, "window size is zero");
+        Windows {
+            iter: self,
+            window: None,
+        }
+    }
+}
+
+impl<const N: usize, T, I> Iterator for Windows<N, I>
+where
+    I: Iterator<Item = T>,
+    T: Copy,
+{
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(window) = &mut self.window {
+            let note = self.iter.next()?;
+            window.rotate_left(1);
+            window[N - 1] = note;
+            return Some(*window);
+        }
+        let first = self.iter.next()?;
+        let mut window = [first; N];
+        for note in &mut window[1..] {
+            *note = self.iter.next()?;
+        }
+        Some(*self.window.insert(window))
+    }
+}
+
+impl<const N: usize, I> FusedIterator for Windows<N, I>
+where
+    Self: Iterator,
+    I: FusedIterator,
+{
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{drop2, invert, spread, ChordGenerator};
+    use crate::{FreqGenerator, Key, Mode, ScaleGenerator, A0};
+
+    #[test]
+    fn triads() {
+        let c_ionian = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::A);
+        let mut chords = ChordGenerator::new(c_ionian.clone(), [0, 2, 4]);
+        // thirds in semitones of I ii iii IV V vi vii°
+        for (degree, thirds) in [
+            (4., 3.),
+            (3., 4.),
+            (3., 4.),
+            (4., 3.),
+            (4., 3.),
+            (3., 4.),
+            (3., 3.),
+            (4., 3.),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let chord = chords.next().unwrap();
+            assert!((c_ionian.triad(degree)[0] / chord[0] - 1.).abs() < 1e-5);
+            let [root, third, fifth] = chord.map(|freq| 12. * (freq / chord[0]).log2());
+            assert!(root.abs() < 1e-3);
+            assert!((third - thirds.0).abs() < 1e-3);
+            assert!((fifth - third - thirds.1).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn windows() {
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN);
+        let notes: [f32; 10] = {
+            let mut scale = scale.clone();
+            core::array::from_fn(|_| scale.next().unwrap())
+        };
+        let mut pairs = scale.clone().windows::<2>();
+        for pair in notes.windows(2) {
+            assert_eq!(Some([pair[0], pair[1]]), pairs.next());
+        }
+        let triples = scale.windows::<3>().take(8);
+        assert!(triples
+            .zip(notes.windows(3))
+            .all(|(triple, notes)| triple == notes));
+    }
+
+    #[test]
+    #[should_panic = "window size is zero"]
+    fn empty_window() {
+        ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN).windows::<0>();
+    }
+
+    #[test]
+    fn inversions() {
+        let mut chord = [261.63f32, 329.63, 392.];
+        invert(&mut chord, 1);
+        assert_eq!([329.63, 392., 523.26], chord);
+        invert(&mut chord, 1);
+        assert_eq!([392., 523.26, 659.26], chord);
+        let mut chord = [1f32, 1.25, 1.5];
+        invert(&mut chord, 4);
+        assert_eq!([2.5, 3., 4.], chord);
+        let mut empty: [f32; 0] = [];
+        invert(&mut empty, 2);
+    }
+
+    #[test]
+    fn voicings() {
+        let c_major = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::IONIAN);
+        let [c, e, g, b] = c_major.seventh(0);
+        assert_eq!([g / 2., c, e, b], drop2([c, e, g, b]));
+        assert_eq!([c, g, e * 2.], spread([c, e, g], 1));
+        assert_eq!([c, g, e * 4., b * 4.], spread([c, e, g, b], 2));
+        assert_eq!([1f32], drop2([1.]));
+    }
+}
@@ -0,0 +1,203 @@
+//! Helpers comparing two frequencies.
+use core::cmp::Ordering;
+
+use num_traits::{cast, Float};
+
+use crate::{FreqGenerator, Note};
+
+/// Distance from `from` to `to` in cents, `1200 * log2(to / from)`: 0 for a unison, 1200 for an octave up
+/// and -1200 for an octave down.
+///
+/// Frequencies are expected to be positive: a negative ratio has no logarithm and gives NaN.
+#[must_use]
+pub fn cents(from: f32, to: f32) -> f32 {
+    1200. * Float::log2(to / from)
+}
+
+/// Assert that two `f32` frequencies are within a tolerance in cents of each other (1 cent if omitted),
+/// which holds the same precision on low and high notes unlike a tolerance in hertz. See [`cents`].
+#[macro_export]
+macro_rules! assert_freq_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_freq_eq!($left, $right, 1.)
+    };
+    ($left:expr, $right:expr, $cents:expr $(,)?) => {{
+        let (left, right, tolerance): (f32, f32, f32) = ($left, $right, $cents);
+        let distance = $crate::cents(left, right);
+        assert!(
+            distance.abs() <= tolerance,
+            "frequencies {left} and {right} are {distance} cents apart, more than {tolerance}"
+        );
+    }};
+}
+
+/// Total order of frequencies, for sorting chord voicings from low to high with
+/// [`sort_unstable_by`](slice::sort_unstable_by) for example.
+/// Unlike `partial_cmp` it never fails: NaN sorts after every frequency (or before them if negative).
+#[must_use]
+pub fn cmp_freq(a: f32, b: f32) -> Ordering {
+    a.total_cmp(&b)
+}
+
+/// Rate in Hz at which two frequencies beat when sounding together, their difference.
+#[must_use]
+pub fn beat_rate(a: f32, b: f32) -> f32 {
+    (a - b).abs()
+}
+
+/// Detuning in cents to apply to `freq` so that it beats `target_beat` times per second against its original pitch.
+/// The detuning is upward, the same amount downward beats slightly slower.
+#[must_use]
+pub fn detune_for_beat(freq: f32, target_beat: f32) -> f32 {
+    cents(freq, freq + target_beat)
+}
+
+/// The most audible combination tones of two frequencies sounding together:
+/// the difference tone `f2 - f1`, the cubic difference tones `2f1 - f2` and `2f2 - f1`, then the summation tone `f1 + f2`.
+/// Differences are returned as absolute values.
+#[must_use]
+pub fn combination_tones(f1: f32, f2: f32) -> [f32; 4] {
+    [
+        (f2 - f1).abs(),
+        (2. * f1 - f2).abs(),
+        (2. * f2 - f1).abs(),
+        f1 + f2,
+    ]
+}
+
+/// Names of the intervals from a unison to an octave, one per semitone.
+const INTERVAL_NAMES: [&str; 13] = [
+    "unison",
+    "minor second",
+    "major second",
+    "minor third",
+    "major third",
+    "perfect fourth",
+    "tritone",
+    "perfect fifth",
+    "minor sixth",
+    "major sixth",
+    "minor seventh",
+    "major seventh",
+    "octave",
+];
+
+/// Name of the closest 12-TET interval between two frequencies, in either order: the distance in cents is rounded
+/// to the nearest 100 so 698 cents still reads as a `"perfect fifth"`.
+///
+/// Compound intervals are named after their simple form (a 12th is a `"perfect fifth"`) and any multiple of an octave
+/// is an `"octave"`. Frequencies with no interval between them (zero, negative or NaN) give `"unknown"`.
+#[must_use]
+pub fn interval_name(a: f32, b: f32) -> &'static str {
+    let Some(semitones) = cast::<_, usize>(Float::round(Float::abs(cents(a, b)) / 100.)) else {
+        return "unknown";
+    };
+    match semitones % 12 {
+        0 if semitones > 0 => INTERVAL_NAMES[12],
+        simple => INTERVAL_NAMES[simple],
+    }
+}
+
+impl Note {
+    /// Rate in Hz at which two notes beat when sounding together, see [`beat_rate`].
+    #[must_use]
+    pub fn beat_rate(self, other: Note) -> f32 {
+        beat_rate(self.freq, other.freq)
+    }
+}
+
+impl<T> FreqGenerator<T>
+where
+    T: Float,
+{
+    /// Distance in cents from `reference` to the current frequency (the last one yielded), see [`cents`].
+    pub fn cents_from(&self, reference: T) -> T {
+        cast::<_, T>(1200).unwrap_or_else(T::nan) * (self.freq / reference).log2()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{beat_rate, cents, cmp_freq, combination_tones, detune_for_beat, interval_name};
+    use crate::{FreqGenerator, Note, A0};
+    use core::cmp::Ordering;
+    use num_traits::Float;
+
+    #[test]
+    fn assert_freq_eq() {
+        crate::assert_freq_eq!(440., 440.0001, 1e-3);
+        crate::assert_freq_eq!(4000., 4001.);
+    }
+
+    #[test]
+    #[should_panic = "cents apart"]
+    fn assert_freq_ne() {
+        crate::assert_freq_eq!(440., 441.);
+    }
+
+    #[test]
+    fn cents_between() {
+        assert_eq!(0., cents(440., 440.));
+        assert_eq!(1200., cents(440., 880.));
+        assert_eq!(-1200., cents(440., 220.));
+        assert!(cents(440., -440.).is_nan());
+    }
+
+    #[test]
+    fn cents_from() {
+        let mut fg = FreqGenerator::new(A0, 12f32);
+        fg.nth(6);
+        assert_eq!(700f32, fg.cents_from(A0).round());
+    }
+
+    #[test]
+    fn beats() {
+        assert_eq!(2., beat_rate(440., 442.));
+        assert_eq!(2., beat_rate(442., 440.));
+        let detuned = FreqGenerator::from_midi(69, 12f32)
+            .detune(detune_for_beat(440., 1.))
+            .next()
+            .unwrap();
+        assert!((detuned - 441.).abs() < 1e-3);
+        let beat = Note::from_freq(440.).beat_rate(Note::from_freq(detuned));
+        assert!((beat - 1.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn combination() {
+        assert_eq!([100., 100., 400., 500.], combination_tones(200., 300.));
+        assert_eq!([100., 400., 100., 500.], combination_tones(300., 200.));
+        assert_eq!([300., 100., 1000., 1100.], combination_tones(400., 700.));
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("perfect fifth", interval_name(200., 300.));
+        assert_eq!("perfect fifth", interval_name(300., 200.));
+        assert_eq!("unison", interval_name(440., 441.));
+        assert_eq!("octave", interval_name(220., 880.));
+        assert_eq!("perfect fifth", interval_name(100., 300.));
+        assert_eq!(
+            "perfect fifth",
+            interval_name(440., 440. * Float::powf(2f32, 6.98 / 12.))
+        );
+        assert_eq!("tritone", interval_name(440., 440. * Float::sqrt(2f32)));
+        assert_eq!("unknown", interval_name(440., f32::NAN));
+    }
+
+    #[test]
+    fn ordering() {
+        assert_eq!(Ordering::Less, cmp_freq(440., 880.));
+        assert_eq!(Ordering::Greater, cmp_freq(f32::NAN, f32::INFINITY));
+        let mut voicing = [659.25, f32::NAN, 440., 554.37];
+        voicing.sort_unstable_by(|&a, &b| cmp_freq(a, b));
+        assert_eq!([440., 554.37, 659.25], voicing[..3]);
+        assert!(voicing[3].is_nan());
+        assert!(Note::from_freq(440.) < Note::from_freq(880.));
+        assert_eq!(
+            None,
+            Note::from_freq(f32::NAN).partial_cmp(&Note::from_freq(440.))
+        );
+    }
+}
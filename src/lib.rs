@@ -1,45 +1,307 @@
 #![no_std]
-#![warn(clippy::pedantic, missing_docs)]
+#![warn(
+    clippy::pedantic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    missing_docs
+)]
 #![doc = include_str!("../README.md")]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature is needed for float math");
+
 use core::{
-    iter::{Copied, Cycle, Skip},
-    ops::{Div, MulAssign},
-    slice,
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+    ops::{Deref, Div, DivAssign, Mul, MulAssign},
+    str::FromStr,
 };
-use derive_new::new;
 use num_derive::{FromPrimitive, ToPrimitive};
-use num_traits::{Pow, ToPrimitive};
+use num_traits::{cast, Float, FromPrimitive, Pow};
+
+#[cfg(feature = "approx")]
+mod approx_impls;
+mod arp;
+mod chord;
+mod interval;
+mod midi;
+mod millihz;
+mod note;
+mod osc;
+mod physics;
+mod quantizer;
+#[cfg(feature = "rational")]
+mod rational;
+#[cfg(feature = "alloc")]
+mod scl;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod series;
+mod simd;
+mod stretch;
+mod tet;
+mod tuning;
+mod units;
+
+pub use arp::{ArpPattern, Arpeggiator};
+pub use chord::{drop2, invert, spread, ChordGenerator, Windows};
+pub use interval::{beat_rate, cents, cmp_freq, combination_tones, detune_for_beat, interval_name};
+pub use midi::{freq_to_midi, freq_to_midi_rounded, to_mts_bulk, MTS_BULK_LEN, NOTE_FREQS};
+pub use millihz::MilliHzGenerator;
+pub use note::{Named, Note, Pitch};
+pub use osc::{SineOsc, Vibrato};
+pub use physics::SPEED_OF_SOUND;
+pub use quantizer::Quantizer;
+#[cfg(feature = "rational")]
+pub use rational::RatioGenerator;
+#[cfg(feature = "alloc")]
+pub use scl::{ParseSclError, Scale, ScaleStep};
+pub use series::{HarmonicSeries, SubharmonicSeries};
+pub use stretch::Stretched;
+pub use tet::{parse_tet, ParseTetError};
+pub use tuning::{
+    compare_tunings, meantone, pythagorean, werckmeister_iii, CentsGenerator, JustGenerator,
+    Tuning, JUST_MAJOR,
+};
+pub use units::{Cents, Hz};
 
 /// Frequency of an A at octave 0. Good base for a frequency generator.
 pub const A0: f32 = 27.5;
 
+/// Frequency of the A at octave 4, the usual tuning reference.
+pub const A4: f32 = 440.;
+
+/// Highest frequency humans can hear, used by [`FreqGenerator::audible`].
+pub const AUDIBLE_MAX: f32 = 20_000.;
+
+/// Diatonic interval pattern in semitones, starting from the ionian mode.
+const DIATONIC: [u8; 7] = [2, 2, 1, 2, 2, 2, 1];
+const PENTATONIC_MAJOR: [u8; 5] = [2, 2, 3, 2, 3];
+const PENTATONIC_MINOR: [u8; 5] = [3, 2, 2, 3, 2];
+const HARMONIC_MINOR: [u8; 7] = [2, 1, 2, 2, 1, 3, 1];
+const MELODIC_MINOR: [u8; 7] = [2, 1, 2, 2, 2, 2, 1];
+const WHOLE_TONE: [u8; 6] = [2; 6];
+const OCTATONIC_WHOLE_HALF: [u8; 8] = [2, 1, 2, 1, 2, 1, 2, 1];
+const OCTATONIC_HALF_WHOLE: [u8; 8] = [1, 2, 1, 2, 1, 2, 1, 2];
+const CHROMATIC: [u8; 12] = [1; 12];
+/// Lambda mode of the Bohlen–Pierce scale, in steps of 13-ED3.
+const BOHLEN_PIERCE_LAMBDA: [u8; 9] = [2, 1, 1, 2, 1, 2, 1, 2, 1];
+/// Maqam rast in quarter tones.
+const RAST: [u8; 7] = [4, 3, 3, 4, 4, 3, 3];
+
+const fn period(intervals: &[u8]) -> u32 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < intervals.len() {
+        sum += intervals[i] as u32;
+        i += 1;
+    }
+    sum
+}
+
+// every preset spans exactly one period of its equal temperament
+const _: () = {
+    assert!(period(&DIATONIC) == 12);
+    assert!(period(&PENTATONIC_MAJOR) == 12);
+    assert!(period(&PENTATONIC_MINOR) == 12);
+    assert!(period(&HARMONIC_MINOR) == 12);
+    assert!(period(&MELODIC_MINOR) == 12);
+    assert!(period(&WHOLE_TONE) == 12);
+    assert!(period(&OCTATONIC_WHOLE_HALF) == 12);
+    assert!(period(&OCTATONIC_HALF_WHOLE) == 12);
+    assert!(period(&CHROMATIC) == 12);
+    assert!(period(&RAST) == 24);
+    assert!(period(&BOHLEN_PIERCE_LAMBDA) == 13);
+};
+
+/// Roman numerals of the chords on each degree of a scale, as major, minor, diminished and augmented triads.
+const ROMAN_NUMERALS: [[&str; 4]; 8] = [
+    ["I", "i", "i°", "I+"],
+    ["II", "ii", "ii°", "II+"],
+    ["III", "iii", "iii°", "III+"],
+    ["IV", "iv", "iv°", "IV+"],
+    ["V", "v", "v°", "V+"],
+    ["VI", "vi", "vi°", "VI+"],
+    ["VII", "vii", "vii°", "VII+"],
+    ["VIII", "viii", "viii°", "VIII+"],
+];
+
+/// Movable-do solfège syllable of each semitone above the tonic, altered ones included.
+const SOLFEGE: [&str; 12] = [
+    "do", "ra", "re", "me", "mi", "fa", "fi", "sol", "le", "la", "te", "ti",
+];
+
 /// Medieval mode. Used for [`Key`].
-#[derive(ToPrimitive, FromPrimitive)]
+///
+/// Each variant is a rotation of the diatonic scale from Ionian, so its modern name doesn't match the medieval
+/// one: [`Mode::D`] is Lydian, the medieval Dorian.
+#[derive(ToPrimitive, FromPrimitive, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Mode {
-    /// Hypodorian
+    /// Ionian (medieval Hypodorian)
+    #[default]
     A,
-    /// Hypophrygian
+    /// Dorian (medieval Hypophrygian)
     B,
-    /// Hypolydian
+    /// Phrygian (medieval Hypolydian)
     C,
-    /// Dorian
+    /// Lydian (medieval Dorian)
     D,
-    /// Phrygian
+    /// Mixolydian (medieval Phrygian)
     E,
-    /// Lydian
+    /// Aeolian (medieval Lydian)
     F,
-    /// Mixolydian
+    /// Locrian (medieval Mixolydian)
     G,
 }
 
-impl Default for Mode {
-    fn default() -> Self {
-        Mode::A
+/// Names accepted by [`Mode`]'s [`FromStr`] implementation. Medieval names that are also modern names
+/// (dorian, phrygian...) are parsed as the modern ones.
+const MODE_NAMES: [(&str, Mode); 10] = [
+    ("ionian", Mode::A),
+    ("dorian", Mode::B),
+    ("phrygian", Mode::C),
+    ("lydian", Mode::D),
+    ("mixolydian", Mode::E),
+    ("aeolian", Mode::F),
+    ("locrian", Mode::G),
+    ("hypodorian", Mode::A),
+    ("hypophrygian", Mode::B),
+    ("hypolydian", Mode::C),
+];
+
+/// Error returned when parsing an unknown [`Mode`] name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseModeError;
+
+impl Display for ParseModeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown mode name")
+    }
+}
+
+impl Error for ParseModeError {}
+
+/// Parses a mode from its case-insensitive modern name (`"dorian"`, `"Aeolian"`...)
+/// or one of the medieval hypo- names.
+impl FromStr for Mode {
+    type Err = ParseModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MODE_NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|&(_, mode)| mode)
+            .ok_or(ParseModeError)
+    }
+}
+
+/// Prints the modern name of the mode, following its rotation from Ionian: `Lydian` for [`Mode::D`],
+/// see [`Mode::modern_name`].
+impl Display for Mode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.modern_name())
     }
 }
 
 impl Mode {
-    fn to_skip(&self) -> usize {
+    /// Ionian (major) mode, alias of [`Mode::A`].
+    pub const IONIAN: Mode = Self::A;
+    /// Dorian mode, alias of [`Mode::B`].
+    pub const DORIAN: Mode = Self::B;
+    /// Phrygian mode, alias of [`Mode::C`].
+    pub const PHRYGIAN: Mode = Self::C;
+    /// Lydian mode, alias of [`Mode::D`].
+    pub const LYDIAN: Mode = Self::D;
+    /// Mixolydian mode, alias of [`Mode::E`].
+    pub const MIXOLYDIAN: Mode = Self::E;
+    /// Aeolian (natural minor) mode, alias of [`Mode::F`].
+    pub const AEOLIAN: Mode = Self::F;
+    /// Locrian mode, alias of [`Mode::G`].
+    pub const LOCRIAN: Mode = Self::G;
+
+    /// Every mode, from [`Mode::A`] to [`Mode::G`].
+    pub const ALL: [Mode; 7] = [
+        Self::A,
+        Self::B,
+        Self::C,
+        Self::D,
+        Self::E,
+        Self::F,
+        Self::G,
+    ];
+
+    /// Iterate over every mode, from [`Mode::A`] to [`Mode::G`].
+    pub fn all() -> impl Iterator<Item = Mode> {
+        Self::ALL.into_iter()
+    }
+
+    /// Interval pattern of the mode in semitones: the diatonic `[2, 2, 1, 2, 2, 2, 1]` rotated left by the
+    /// variant's index, as [`ScaleGenerator::new`] does. Each variant yields the pattern of its modern mode
+    /// (see the variant docs), not of its medieval one: dorian ([`Mode::B`]) is `[2, 1, 2, 2, 2, 1, 2]`
+    /// and [`Mode::D`], the medieval Dorian, is lydian's `[2, 2, 2, 1, 2, 2, 1]`.
+    #[must_use]
+    pub fn intervals(self) -> [u8; 7] {
+        let mut intervals = DIATONIC;
+        intervals.rotate_left(self as usize);
+        intervals
+    }
+
+    /// Mode `steps` diatonic degrees along, wrapping around the seven modes either way:
+    /// ionian rotated by 5 is aeolian, and by -1 locrian.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn rotate(self, steps: i32) -> Mode {
+        // rem_euclid is within 0..7
+        Self::ALL[(self as usize + steps.rem_euclid(7) as usize) % 7]
+    }
+
+    /// Modern name of the mode as used by [`ScaleGenerator::new`]: Ionian, Dorian, Phrygian, Lydian,
+    /// Mixolydian, Aeolian or Locrian. Names follow the variant's rotation from Ionian, not its medieval name,
+    /// so [`Mode::D`] is Lydian and `"dorian"` parses as [`Mode::B`].
+    #[must_use]
+    pub fn modern_name(self) -> &'static str {
+        match self {
+            Self::A => "Ionian",
+            Self::B => "Dorian",
+            Self::C => "Phrygian",
+            Self::D => "Lydian",
+            Self::E => "Mixolydian",
+            Self::F => "Aeolian",
+            Self::G => "Locrian",
+        }
+    }
+
+    /// Medieval name of the mode, as documented on each variant.
+    #[must_use]
+    pub fn medieval_name(self) -> &'static str {
+        match self {
+            Self::A => "Hypodorian",
+            Self::B => "Hypophrygian",
+            Self::C => "Hypolydian",
+            Self::D => "Dorian",
+            Self::E => "Phrygian",
+            Self::F => "Lydian",
+            Self::G => "Mixolydian",
+        }
+    }
+
+    fn letter(self) -> char {
+        match self {
+            Self::A => 'A',
+            Self::B => 'B',
+            Self::C => 'C',
+            Self::D => 'D',
+            Self::E => 'E',
+            Self::F => 'F',
+            Self::G => 'G',
+        }
+    }
+
+    fn to_skip(self) -> u8 {
         match self {
             Self::A => 0,
             Self::B => 2,
@@ -52,158 +314,1694 @@ impl Mode {
     }
 }
 
-/// A key and a sharp.
-#[derive(new, Default)]
+/// Accidental altering the note of a [`Key`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Accidental {
+    /// ♮, no alteration
+    #[default]
+    Natural,
+    /// ♯, one semitone up
+    Sharp,
+    /// ♭, one semitone down
+    Flat,
+    /// 𝄪, two semitones up
+    DoubleSharp,
+    /// 𝄫, two semitones down
+    DoubleFlat,
+}
+
+impl Accidental {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Natural => "",
+            Self::Sharp => "#",
+            Self::Flat => "b",
+            Self::DoubleSharp => "##",
+            Self::DoubleFlat => "bb",
+        }
+    }
+
+    /// Number of semitones this accidental moves a note by.
+    #[must_use]
+    pub fn semitones(self) -> i8 {
+        match self {
+            Self::Natural => 0,
+            Self::Sharp => 1,
+            Self::Flat => -1,
+            Self::DoubleSharp => 2,
+            Self::DoubleFlat => -2,
+        }
+    }
+
+    /// Accidental moving a note by `semitones`, if there's one.
+    fn from_semitones(semitones: i8) -> Option<Self> {
+        match semitones {
+            0 => Some(Self::Natural),
+            1 => Some(Self::Sharp),
+            -1 => Some(Self::Flat),
+            2 => Some(Self::DoubleSharp),
+            -2 => Some(Self::DoubleFlat),
+            _ => None,
+        }
+    }
+}
+
+/// Prints the accidental the way [`Key`] parses it: nothing, `#`, `b`, `##` or `bb`.
+impl Display for Accidental {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// A key: a note letter and an accidental.
+/// Keys compare by spelling, so C# and Db are different keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Key {
     note: Mode,
-    sharp: bool,
+    accidental: Accidental,
+}
+
+/// Error returned when parsing an invalid [`Key`] spelling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyError;
+
+impl Display for ParseKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid key spelling")
+    }
+}
+
+impl Error for ParseKeyError {}
+
+/// Prints the key the way it's parsed, like `A#` or `Eb`.
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.note.letter(), self.accidental)
+    }
+}
+
+/// Iterates the major (ionian) scale of the key, see [`ScaleGenerator::from_key`].
+impl IntoIterator for Key {
+    type Item = f32;
+    type IntoIter = ScaleGenerator<f32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ScaleGenerator::from_key(self, Mode::IONIAN)
+    }
+}
+
+/// Iterate keys around the circle of fifths from `start`: each key is a perfect fifth (7 semitones) above the previous one,
+/// the letter moving up by a fifth and sharps accumulating as needed (C, G, D, A, E, B, F#, C#...).
+/// After twelve keys the circle wraps back to `start`.
+pub fn circle_of_fifths(start: Key) -> impl Iterator<Item = Key> {
+    (0..12)
+        .map(move |fifths: u8| {
+            Key::spell(
+                Mode::ALL[(start.note as usize + 4 * usize::from(fifths)) % 7],
+                start.semitones() + (7 * fifths % 12).cast_signed(),
+            )
+        })
+        .cycle()
+}
+
+/// Parses a key from a note letter (`A` to `G`, case-insensitive) optionally followed by
+/// `#` (sharp), `b` (flat), `##` (double sharp) or `bb` (double flat).
+impl FromStr for Key {
+    type Err = ParseKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let note = match chars.next().map(|c| c.to_ascii_uppercase()) {
+            Some('A') => Mode::A,
+            Some('B') => Mode::B,
+            Some('C') => Mode::C,
+            Some('D') => Mode::D,
+            Some('E') => Mode::E,
+            Some('F') => Mode::F,
+            Some('G') => Mode::G,
+            _ => return Err(ParseKeyError),
+        };
+        let accidental = match chars.as_str() {
+            "" => Accidental::Natural,
+            "#" => Accidental::Sharp,
+            "b" => Accidental::Flat,
+            "##" => Accidental::DoubleSharp,
+            "bb" => Accidental::DoubleFlat,
+            _ => return Err(ParseKeyError),
+        };
+        Ok(Self::with_accidental(note, accidental))
+    }
 }
 
 impl Key {
-    fn to_freq<F>(&self) -> F
+    /// Make a key from a note and whether it's sharp, shorthand for [`Accidental::Sharp`] or [`Accidental::Natural`].
+    #[must_use]
+    pub fn new(note: Mode, sharp: bool) -> Self {
+        Self::with_accidental(
+            note,
+            if sharp {
+                Accidental::Sharp
+            } else {
+                Accidental::Natural
+            },
+        )
+    }
+
+    /// Make a key from a note and any accidental.
+    #[must_use]
+    pub fn with_accidental(note: Mode, accidental: Accidental) -> Self {
+        Self { note, accidental }
+    }
+
+    /// The other usual spelling of the same pitch: sharps become flats of the next letter (A# to Bb),
+    /// flats become sharps of the previous letter, double sharps and flats become naturals (C## to D)
+    /// and naturals that are a semitone away from another letter get its accidental (B to Cb, C to B#).
+    /// A, D and G, which only have double-accidental spellings, are returned as they are.
+    #[must_use]
+    pub fn enharmonic(self) -> Self {
+        let letter = |shift: usize| Mode::ALL[(self.note as usize + shift) % 7];
+        let (next, previous) = (letter(1), letter(6));
+        match self.accidental {
+            Accidental::Sharp | Accidental::DoubleSharp => Self::spell(next, self.semitones()),
+            Accidental::Flat | Accidental::DoubleFlat => Self::spell(previous, self.semitones()),
+            Accidental::Natural => [next, previous]
+                .into_iter()
+                .map(|note| Self::spell(note, self.semitones()))
+                .find(|key| key.accidental.semitones().abs() == 1)
+                .unwrap_or(self),
+        }
+    }
+
+    /// Whether both keys are the same pitch class, whatever their spelling (C# and Db for example).
+    ///
+    /// Keys from both sides of A may still give frequencies an octave apart, like G# and Ab.
+    #[must_use]
+    pub fn is_enharmonic_with(self, other: Key) -> bool {
+        (self.semitones() - other.semitones()).rem_euclid(12) == 0
+    }
+
+    /// Relative minor of the major key, a minor third down: C major gives A aeolian.
+    #[must_use]
+    pub fn relative_minor(self) -> (Key, Mode) {
+        (
+            Self::spell(
+                Mode::ALL[(self.note as usize + 5) % 7],
+                self.semitones() - 3,
+            ),
+            Mode::AEOLIAN,
+        )
+    }
+
+    /// Relative major of the minor key, a minor third up: A minor gives C ionian.
+    #[must_use]
+    pub fn relative_major(self) -> (Key, Mode) {
+        (
+            Self::spell(
+                Mode::ALL[(self.note as usize + 2) % 7],
+                self.semitones() + 3,
+            ),
+            Mode::IONIAN,
+        )
+    }
+
+    /// Key signature of the major key: positive for sharps, negative for flats (C is 0, G is 1, F is -1, C# is 7...).
+    /// This is the position of the key on the circle of fifths.
+    #[must_use]
+    pub fn signature(self) -> i8 {
+        // A, B, C, D, E, F, G
+        const NATURALS: [i8; 7] = [3, 5, 0, 2, 4, -1, 1];
+        NATURALS[self.note as usize] + 7 * self.accidental.semitones()
+    }
+
+    /// Key signature of the key in `mode`, the signature of its relative major: A aeolian has none, E dorian has 2 sharps.
+    #[must_use]
+    pub fn signature_in(self, mode: Mode) -> i8 {
+        // ionian, dorian, phrygian, lydian, mixolydian, aeolian, locrian
+        const MODES: [i8; 7] = [0, -2, -4, 1, -1, -3, -5];
+        self.signature() + MODES[mode as usize]
+    }
+
+    /// Spell the pitch `semitones` above A with the letter `note`, or with a natural or a sharp
+    /// if no accidental brings that letter there.
+    fn spell(note: Mode, semitones: i8) -> Self {
+        // from -6 to 5 semitones from the letter
+        let offset = |note: Mode| (semitones - note.to_skip().cast_signed() + 6).rem_euclid(12) - 6;
+        if let Some(accidental) = Accidental::from_semitones(offset(note)) {
+            return Self::with_accidental(note, accidental);
+        }
+        Mode::ALL
+            .into_iter()
+            .find(|&note| matches!(offset(note), 0 | 1))
+            .map(|note| Self::new(note, offset(note) == 1))
+            .unwrap_or_default()
+    }
+
+    /// Semitones from A.
+    fn semitones(self) -> i8 {
+        self.note.to_skip().cast_signed() + self.accidental.semitones()
+    }
+
+    fn to_freq<F>(self) -> F
     where
         F: From<f32> + From<u8> + Pow<F, Output = F> + MulAssign + Div<Output = F> + Copy,
     {
-        FreqGenerator::new(A0.into(), 12f32.into())
-            .skip(self.note.to_skip() + self.sharp as usize)
-            .next()
-            .unwrap()
+        let semitones = self.semitones();
+        let mut freq: F = A0.into();
+        let ratio = (<u8 as Into<F>>::into(2))
+            .pow(<u8 as Into<F>>::into(semitones.unsigned_abs()) / 12.into());
+        if semitones < 0 {
+            freq = freq / ratio;
+        } else {
+            freq *= ratio;
+        }
+        freq
     }
 }
 
 /// Frequency generator. Acts as an iterator yielding notes from low to high pitch.
 /// The number of notes in the resulting equal-tempered scale (aka TET) is adjustable.
-#[derive(new, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FreqGenerator<T = f32> {
     freq: T,
     scale: T,
+    period: T,
+    ratio: T,
+    base: T,
+    step: isize,
+    /// Step the generator was made on, restored by [`reset`](Self::reset).
+    start: isize,
+    exact: bool,
+}
+
+impl<T> FreqGenerator<T>
+where
+    T: From<u8> + Pow<T, Output = T> + Div<Output = T> + Copy,
+{
+    /// Make a new generator starting (exclusively) at `freq` with `scale` notes per octave.
+    /// The ratio between two consecutive notes is computed once here.
+    pub fn new(freq: T, scale: T) -> Self {
+        Self::with_ratio(freq, 2.into(), scale)
+    }
+
+    /// Make a new 24-TET (quarter-tone) generator starting (exclusively) at `freq`.
+    pub fn quarter_tone(freq: T) -> Self {
+        Self::new(freq, 24.into())
+    }
+
+    /// Make a new generator like [`new`](Self::new) whose first [`next`](Iterator::next) yields `freq` itself,
+    /// so `skip(48)` lands four octaves above `freq` in 12-TET.
+    ///
+    /// It sits one step below `freq` until then, see [`current`](Self::current). A [`ScaleGenerator`] builds its
+    /// scale on that note, so wrap a generator from [`new`](Self::new) instead for a scale on `freq`.
+    pub fn inclusive(freq: T, scale: T) -> Self {
+        let mut generator = Self::new(freq, scale);
+        generator.freq = freq / generator.ratio;
+        generator.step = -1;
+        generator.start = -1;
+        generator
+    }
+
+    /// Make a new generator laid out like `new(A0, scale)` but tuned so that A4 is `a4` instead of [`A4`],
+    /// for 432 Hz or 442 Hz orchestra tunings for example. The base is the A four octaves below `a4`.
+    pub fn from_reference(a4: f32, scale: T) -> Self
+    where
+        T: From<f32>,
+    {
+        Self::new((a4 * (A0 / A4)).into(), scale)
+    }
+
+    /// Last frequency yielded, without advancing the generator.
+    /// Before the first [`next`](Iterator::next), this is the frequency the generator was made with
+    /// (one step below it for an [`inclusive`](Self::inclusive) generator).
+    pub fn current(&self) -> T {
+        self.freq
+    }
+
+    /// Frequency the generator was made with, where [`reset`](Self::reset) brings it back
+    /// (moved along by transpositions and detunings). See [`current`](Self::current) for the last note yielded.
+    pub fn base(&self) -> T {
+        self.base
+    }
+
+    /// Number of steps per period, the `scale` the generator was made with: 12 for 12-TET.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Make a new generator starting (exclusively) at `freq` that divides a repeating interval of
+    /// `period_ratio` (2 for the octave) in `steps` equal steps.
+    ///
+    /// For example, the Bohlen–Pierce scale divides the tritave (3:1) in 13 steps.
+    pub fn with_ratio(freq: T, period_ratio: T, steps: T) -> Self {
+        Self {
+            freq,
+            scale: steps,
+            period: period_ratio,
+            ratio: period_ratio.pow(<u8 as Into<T>>::into(1) / steps),
+            base: freq,
+            step: 0,
+            start: 0,
+            exact: false,
+        }
+    }
+
+    /// Switch to the exact path: every note is recomputed as `base * period^(step/scale)` from the
+    /// construction-time base instead of being multiplied from the previous one.
+    ///
+    /// The default fast path costs a single multiplication per note but its rounding error
+    /// compounds, so after a few octaves an A may no longer be an exact `A0 * 2^k`.
+    /// The exact path costs one `pow` per note and never drifts.
+    /// Either way, the starting frequency itself is always yielded exactly.
+    #[must_use]
+    pub fn exact(mut self) -> Self {
+        self.exact = true;
+        self
+    }
+}
+
+/// 12-TET from [`A0`], yielding A#0 first.
+impl Default for FreqGenerator<f32> {
+    fn default() -> Self {
+        Self::new(A0, 12.)
+    }
+}
+
+impl<T> FreqGenerator<T>
+where
+    T: From<u8> + Pow<T, Output = T> + MulAssign + Div<Output = T> + FromPrimitive + Copy,
+{
+    /// Bring the generator back to where it was made, so the next note is the first one it yielded.
+    /// Transpositions and detunings are kept, as they retune the generator rather than move along it.
+    pub fn reset(&mut self) {
+        self.seek(self.start);
+    }
+
+    /// Move the generator to `step` steps from its base without yielding.
+    fn seek(&mut self, step: isize) {
+        self.step = step;
+        if self.recompute().is_none() {
+            self.freq = self.base;
+        }
+    }
+
+    fn recompute(&mut self) -> Option<T> {
+        self.freq = self.base;
+        self.freq *= self.period.pow(T::from_isize(self.step)? / self.scale);
+        Some(self.freq)
+    }
 }
 
 impl<T> Iterator for FreqGenerator<T>
 where
-    T: From<u8> + Pow<T, Output = T> + MulAssign + Div<Output = T> + Copy,
+    T: From<u8> + Pow<T, Output = T> + MulAssign + Div<Output = T> + FromPrimitive + Copy,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.freq *= (<u8 as Into<T>>::into(2)).pow(<u8 as Into<T>>::into(1) / self.scale);
+        self.step += 1;
+        if self.exact || self.step == 0 {
+            return self.recompute();
+        }
+        self.freq *= self.ratio;
+        Some(self.freq)
+    }
+
+    /// Jumps straight to the `n`th next note with a single exponentiation instead of
+    /// multiplying `n + 1` times, so skipping far up the keyboard doesn't accumulate drift.
+    ///
+    /// Returns `None` without moving when the step count overflows, as for `nth(usize::MAX)`.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let steps = n.checked_add(1)?;
+        self.step = self.step.checked_add(isize::try_from(steps).ok()?)?;
+        if self.exact || self.step == 0 {
+            return self.recompute();
+        }
+        self.freq *= self.period.pow(T::from_usize(steps)? / self.scale);
         Some(self.freq)
     }
 }
 
-/// Scale generator. Acts as an iterator yielding notes from low to high pitch.
-/// Uses a [`FreqGenerator`] to know the next candidate frequency and skips these candidates if they're not part of the scale.
-/// Supports modes. To change the key, shift the first note yielded by its [`FreqGenerator`] with [`skip`](https://doc.rust-lang.org/nightly/core/iter/trait.Iterator.html#method.skip).
-pub struct ScaleGenerator<T = f32, I = FreqGenerator<T>>
+/// Walks the generator downward: each call divides the current frequency by one step
+/// and yields the result, so a [`next`](Iterator::next) followed by a
+/// [`next_back`](DoubleEndedIterator::next_back) lands back on the starting frequency.
+impl<T> DoubleEndedIterator for FreqGenerator<T>
 where
-    I: Iterator<Item = T>,
-    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+    T: From<u8>
+        + Pow<T, Output = T>
+        + MulAssign
+        + DivAssign
+        + Div<Output = T>
+        + FromPrimitive
+        + Copy,
 {
-    fg: I,
-    intervals: Skip<Cycle<Copied<slice::Iter<'static, u8>>>>,
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.step -= 1;
+        if self.exact || self.step == 0 {
+            return self.recompute();
+        }
+        self.freq /= self.ratio;
+        Some(self.freq)
+    }
 }
 
-impl<T, I> ScaleGenerator<T, I>
+/// The generator never runs out of notes.
+impl<T> FusedIterator for FreqGenerator<T> where Self: Iterator {}
+
+impl<T> FreqGenerator<T>
 where
-    I: Iterator<Item = T>,
-    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+    Self: Iterator<Item = T>,
 {
-    /// Make a new generator from a [`FreqGenerator`] or similar. Any iterator wrapper works so you can use [`skip`](https://doc.rust-lang.org/nightly/core/iter/trait.Iterator.html#method.skip) on it.
-    /// The `mode` parametter is the shift from the current mode (C / ionian). 1 is D, 2 is E...
-    ///
-    /// Keep in mind that modes only work on 12 TET. Nothing here enforces this.
-    pub fn new(frequencies: I, mode: Mode) -> Self {
-        Self {
-            fg: frequencies,
-            intervals: [2, 2, 1, 2, 2, 2, 1]
-                .iter()
-                .copied()
-                .cycle()
-                .skip(mode.to_usize().unwrap()),
-        }
+    /// Write the next `buf.len()` notes into `buf` in one call, without allocating.
+    /// Returns the number of notes written, always `buf.len()` as the generator is infinite.
+    pub fn fill(&mut self, buf: &mut [T]) -> usize {
+        fill(self, buf)
     }
 }
 
-impl<T> ScaleGenerator<T>
+impl<T> UpTo<T>
 where
-    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy + From<f32>,
+    Self: Iterator<Item = T>,
 {
-    /// Make a 12-TET generator from a [`FreqGenerator`] using a key and a mode.
-    pub fn from_key(key: Key, mode: Mode) -> Self {
-        Self::new(FreqGenerator::new(key.to_freq(), 12u8.into()), mode)
+    /// Write the next notes into `buf` in one call, without allocating.
+    /// Returns the number of notes written, less than `buf.len()` if the bound is reached.
+    pub fn fill(&mut self, buf: &mut [T]) -> usize {
+        fill(self, buf)
     }
 }
 
-impl<T, I> Iterator for ScaleGenerator<T, I>
+fn fill<T>(notes: impl Iterator<Item = T>, buf: &mut [T]) -> usize {
+    let mut written = 0;
+    for (slot, freq) in buf.iter_mut().zip(notes) {
+        *slot = freq;
+        written += 1;
+    }
+    written
+}
+
+impl<T> FreqGenerator<T>
 where
-    I: Iterator<Item = T>,
-    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+    T: Float,
 {
-    type Item = T;
-    fn next(&mut self) -> Option<Self::Item> {
-        for _ in 1..self.intervals.next().unwrap() {
-            self.fg.next()?;
+    /// Stop yielding notes once they get higher than `max`.
+    ///
+    /// The number of notes left is computed upfront from the logarithm of `max` over the current frequency,
+    /// with a tolerance of a thousandth of a step so that rounding errors don't drop a note landing right on `max`.
+    pub fn up_to(self, max: T) -> UpTo<T> {
+        let steps = self.scale * (max / self.freq).log2() / self.period.log2();
+        let rounded = steps.round();
+        let steps = if (steps - rounded).abs() < cast(1e-3).unwrap_or_else(T::zero) {
+            rounded
+        } else {
+            steps.floor()
+        };
+        UpTo {
+            remaining: steps.to_usize().unwrap_or(0),
+            fg: self,
         }
-        self.fg.next()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::Key;
+impl<T> FreqGenerator<T>
+where
+    T: Float,
+{
+    /// Shift every future note by `semitones` steps of the generator (semitones in 12-TET), up or down.
+    /// Unlike [`skip`](Iterator::skip), the shift can be negative.
+    #[must_use]
+    pub fn transpose(mut self, semitones: i32) -> Self {
+        self.shift_steps(semitones);
+        self
+    }
 
-    use super::{FreqGenerator, Mode, ScaleGenerator, A0};
-    use num_traits::FromPrimitive;
+    /// Move the generator `n` periods (octaves) up in a single step, `n * scale` notes:
+    /// `FreqGenerator::new(A0, 12.).skip_octaves(4)` goes on like `FreqGenerator::new(A4, 12.)`.
+    /// The number of steps saturates at `usize::MAX`, see [`nth`](Iterator::nth) for how far it can go.
+    #[must_use]
+    pub fn skip_octaves(mut self, n: u32) -> Self
+    where
+        Self: Iterator,
+    {
+        let steps = (self.scale * cast(n).unwrap_or_else(T::nan)).round();
+        let steps = if steps > T::zero() {
+            steps.to_usize().unwrap_or(usize::MAX)
+        } else {
+            0
+        };
+        if let Some(last) = steps.checked_sub(1) {
+            self.nth(last);
+        }
+        self
+    }
 
-    #[test]
-    fn precision() {
-        assert_eq!(
-            440f32,
-            FreqGenerator::new(A0, 12f32)
-                .skip(12 * 4 - 1)
-                .next()
-                .unwrap()
-                .round()
-        );
+    /// Multiply every future note by `steps` steps of the generator.
+    fn shift_steps(&mut self, steps: i32) {
+        let ratio = self
+            .period
+            .powf(cast::<_, T>(steps).unwrap_or_else(T::nan) / self.scale);
+        self.shift(ratio);
     }
 
-    #[test]
-    fn precision_f64() {
-        assert_eq!(
-            440f64,
-            FreqGenerator::new(A0 as f64, 12.0)
-                .skip(12 * 4 - 1)
-                .next()
-                .unwrap()
-                .round()
-        );
+    /// Multiply every future note by `ratio`.
+    fn shift(&mut self, ratio: T) {
+        self.freq = self.freq * ratio;
+        self.base = self.base * ratio;
     }
+}
 
-    #[test]
-    fn scale_octave() {
-        assert_eq!(
-            440f32,
-            ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::A)
-                .skip(4 * 7 - 1)
-                .next()
-                .unwrap()
-                .round()
-        )
+impl<T> FreqGenerator<T>
+where
+    T: Float + From<f32>,
+{
+    /// Frequency at the continuous position `index` among the next notes, without advancing the generator:
+    /// `at(0.)` is the next note, `at(1.)` the one after and `at(0.5)` halfway between them in log space.
+    /// Handy for portamento and glissando.
+    ///
+    /// Computed from the base like the [`exact`](Self::exact) path, so it doesn't drift.
+    pub fn at(&self, index: f32) -> T {
+        let steps = cast::<_, T>(self.step).unwrap_or_else(T::nan) + T::one() + index.into();
+        self.base * self.period.powf(steps / self.scale)
     }
 
-    #[test]
-    fn major() {
-        assert_eq!(
-            554f32, // C#, part of A chord
-            ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::from_usize(0).unwrap())
-                .skip(4 * 7 - 1 + 2)
-                .next()
-                .unwrap()
-                .round()
-        )
+    /// Shift every future note by `cents`, up or down, whatever the scale of the generator.
+    /// Small detunings (5 to 15 cents) thicken unison stacks. Composes with [`transpose`](Self::transpose).
+    #[must_use]
+    pub fn detune(mut self, cents: f32) -> Self {
+        self.shift(Float::powf(2f32, cents / 1200.).into());
+        self
     }
+}
 
-    #[test]
+impl<T> FreqGenerator<T>
+where
+    T: Float + From<f32>,
+{
+    /// Stop yielding notes once they get higher than [`AUDIBLE_MAX`].
+    pub fn audible(self) -> UpTo<T> {
+        self.up_to(AUDIBLE_MAX.into())
+    }
+}
+
+/// Bounded [`FreqGenerator`], see [`FreqGenerator::up_to`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpTo<T = f32> {
+    fg: FreqGenerator<T>,
+    remaining: usize,
+}
+
+impl<T> Iterator for UpTo<T>
+where
+    FreqGenerator<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.fg.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// The length is `floor(scale * log2(max / freq))` for octave-based generators.
+impl<T> ExactSizeIterator for UpTo<T> where FreqGenerator<T>: Iterator<Item = T> {}
+
+impl<T> FusedIterator for UpTo<T> where FreqGenerator<T>: Iterator<Item = T> {}
+
+/// Longest interval pattern a [`ScaleGenerator`] can cycle through, enough for a 31-TET chromatic scale.
+pub const MAX_INTERVALS: usize = 32;
+
+/// Interval pattern copied into a [`ScaleGenerator`], so patterns built at runtime don't need to be `'static`.
+/// Derefs to the used part of the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pattern {
+    steps: [u8; MAX_INTERVALS],
+    len: usize,
+    /// Sum of the steps.
+    period: usize,
+}
+
+impl Pattern {
+    /// Copy `intervals` into a new buffer.
+    ///
+    /// # Panics
+    /// If `intervals` is longer than [`MAX_INTERVALS`].
+    fn new(intervals: &[u8]) -> Self {
+        assert!(
+            intervals.len() <= MAX_INTERVALS,
+            "interval pattern longer than MAX_INTERVALS"
+        );
+        let mut steps = [0; MAX_INTERVALS];
+        steps[..intervals.len()].copy_from_slice(intervals);
+        Self {
+            steps,
+            len: intervals.len(),
+            period: intervals.iter().map(|&step| usize::from(step)).sum(),
+        }
+    }
+}
+
+impl Deref for Pattern {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.steps[..self.len]
+    }
+}
+
+/// Scale generator. Acts as an iterator yielding notes from low to high pitch.
+/// Uses a [`FreqGenerator`] to know the next candidate frequency and skips these candidates if they're not part of the scale.
+/// Supports modes. To change the key, shift the first note yielded by its [`FreqGenerator`] with [`skip`](https://doc.rust-lang.org/nightly/core/iter/trait.Iterator.html#method.skip).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleGenerator<T = f32, I = FreqGenerator<T>>
+where
+    I: Iterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    fg: I,
+    intervals: Pattern,
+    /// Index of the next interval to walk.
+    phase: usize,
+    /// Phase the generator was made with, restored by [`reset`](Self::reset).
+    start: usize,
+    /// Steps of the underlying generator walked since the generator was made, walked back by [`reset`](Self::reset).
+    walked: isize,
+}
+
+impl<T, I> ScaleGenerator<T, I>
+where
+    I: Iterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    /// Make a new generator from a [`FreqGenerator`] or similar. Any iterator wrapper works so you can use [`skip`](https://doc.rust-lang.org/nightly/core/iter/trait.Iterator.html#method.skip) on it.
+    /// The `mode` parametter is the shift from the current mode (C / ionian). 1 is D, 2 is E...
+    ///
+    /// Keep in mind that modes only work on 12 TET. Nothing here enforces this.
+    ///
+    /// The scale is built on the note the generator is on, its first note being the interval above it.
+    /// An [`inclusive`](FreqGenerator::inclusive) generator is on the step below the frequency it was made with,
+    /// so the scale is built a step lower.
+    pub fn new(frequencies: I, mode: Mode) -> Self {
+        let mut generator = Self::from_intervals(frequencies, &DIATONIC);
+        generator.phase = mode as usize;
+        generator.start = generator.phase;
+        generator
+    }
+
+    /// Yield each note along with its octave number: 0 until the interval pattern wraps
+    /// back to the note the generator started on, then 1, and so on.
+    ///
+    /// The octave is the period of the interval pattern, 12 steps for the diatonic presets.
+    pub fn with_octave(self) -> WithOctave<T, I> {
+        WithOctave {
+            start: self.phase,
+            scale: self,
+            octave: 0,
+        }
+    }
+
+    /// Move the generator `n` octaves (full cycles of the interval pattern) up, staying on the same degree.
+    /// Relies on [`nth`](Iterator::nth) of the underlying generator, which is a single step for a [`FreqGenerator`].
+    /// The generator then [`reset`](ScaleGenerator::reset)s to where it lands rather than where it was made.
+    /// The number of steps saturates at `usize::MAX`.
+    #[must_use]
+    pub fn skip_octaves(mut self, n: u32) -> Self {
+        if let Some(last) = self
+            .octave_steps()
+            .saturating_mul(n as usize)
+            .checked_sub(1)
+        {
+            self.fg.nth(last);
+        }
+        self
+    }
+
+    /// Number of notes in one cycle of the interval pattern: 7 for diatonic scales, 5 for pentatonic ones...
+    /// `take(scale.scale_len())` takes exactly one octave.
+    pub fn scale_len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Interval pattern the generator cycles through, in steps of the underlying generator,
+    /// from the start of the pattern whatever the mode: `[2, 2, 1, 2, 2, 2, 1]` for every diatonic mode.
+    pub fn intervals_slice(&self) -> &[u8] {
+        &self.intervals
+    }
+
+    /// Number of steps of the underlying generator in one cycle of the interval pattern,
+    /// 12 for the 12-TET presets. Same as [`octave_steps`](Self::octave_steps).
+    pub fn period_semitones(&self) -> u32 {
+        // at most `MAX_INTERVALS` steps of 255, this always fits
+        u32::try_from(self.intervals.period).unwrap_or(u32::MAX)
+    }
+
+    /// Roman numeral of the triad built on `degree` (0 for the tonic, see [`triad`](Self::triad)):
+    /// uppercase for major, lowercase for minor, with `°` for diminished and `+` for augmented.
+    /// In ionian the degrees read I ii iii IV V vi vii°.
+    ///
+    /// The quality is read from the interval pattern in 12-TET semitones: other thirds count as major when wider
+    /// than 3 steps and as minor otherwise. Degrees wrap around the scale length.
+    ///
+    /// Numerals only go up to VIII, so `None` for the degrees past the 8th of longer scales like the chromatic one.
+    pub fn roman_numeral(&self, degree: usize) -> Option<&'static str> {
+        let degree = degree % self.intervals.len();
+        let third = self.steps_to(degree + 2) - self.steps_to(degree);
+        let fifth = self.steps_to(degree + 4) - self.steps_to(degree);
+        let quality = match (third, fifth) {
+            (3, 6) => 2,
+            (4, 8) => 3,
+            (0..=3, _) => 1,
+            _ => 0,
+        };
+        ROMAN_NUMERALS.get(degree).map(|numerals| numerals[quality])
+    }
+
+    /// Movable-do solfège syllable of `degree` (0 for the tonic): do, re, mi, fa, sol, la, ti in ionian.
+    /// Altered degrees of other scales get the chromatic syllables ra, me, fi, le and te,
+    /// so aeolian reads do, re, me, fa, sol, le, te.
+    ///
+    /// The syllable is read from the distance to the tonic in 12-TET semitones. Degrees wrap around the scale length.
+    pub fn solfege(&self, degree: usize) -> &'static str {
+        SOLFEGE[self.steps_to(degree % self.intervals.len()) % SOLFEGE.len()]
+    }
+
+    /// Number of steps of the underlying generator in one cycle of the interval pattern, summed once when the
+    /// generator is made. Every degree-based method repeats the scale every `octave_steps` steps: for a 12-TET
+    /// pattern that doesn't sum to 12, [`note(degree + scale_len)`](Self::note) isn't an octave above
+    /// [`note(degree)`](Self::note) but this many steps above, so the scale drifts away from the 2:1 octave
+    /// as it climbs and doesn't repeat at the octave.
+    pub fn octave_steps(&self) -> usize {
+        self.intervals.period
+    }
+
+    /// Number of steps of the underlying generator between the current note and the `degree`th note above it.
+    fn steps_to(&self, degree: usize) -> usize {
+        self.steps_from(self.phase, degree)
+    }
+
+    /// Number of steps of the underlying generator from the note at `phase` in the interval pattern
+    /// to the `degree`th note above it.
+    fn steps_from(&self, phase: usize, degree: usize) -> usize {
+        degree / self.intervals.len() * self.octave_steps()
+            + self
+                .intervals
+                .iter()
+                .cycle()
+                .skip(phase)
+                .take(degree % self.intervals.len())
+                .map(|&step| usize::from(step))
+                .sum::<usize>()
+    }
+
+    /// Number of notes the generator yields out of `steps` steps of the underlying generator.
+    fn notes_within(&self, steps: usize) -> usize {
+        let period = self.octave_steps();
+        let mut notes = steps / period * self.intervals.len();
+        let mut rest = steps % period;
+        for &step in self.intervals.iter().cycle().skip(self.phase) {
+            match rest.checked_sub(step.into()) {
+                Some(left) => rest = left,
+                None => break,
+            }
+            notes += 1;
+        }
+        notes
+    }
+
+    /// Make a new generator cycling through a custom interval pattern, in steps of the underlying generator
+    /// (semitones for a 12-TET [`FreqGenerator`]). For example `&[2, 1, 2, 2, 1, 3, 1]` is a harmonic minor scale.
+    ///
+    /// The pattern is copied into the generator, so it can be built at runtime.
+    ///
+    /// # Panics
+    /// If `intervals` is empty, as the generator would have nothing to cycle through,
+    /// if it contains a zero step, as the generator would yield the same note twice,
+    /// or if it's longer than [`MAX_INTERVALS`].
+    pub fn from_intervals(frequencies: I, intervals: &[u8]) -> Self {
+        assert!(!intervals.is_empty(), "empty interval pattern");
+        assert!(!intervals.contains(&0), "zero interval in pattern");
+        Self {
+            fg: frequencies,
+            intervals: Pattern::new(intervals),
+            phase: 0,
+            start: 0,
+            walked: 0,
+        }
+    }
+
+    /// Make a major pentatonic generator (`[2, 2, 3, 2, 3]`) from a 12-TET [`FreqGenerator`] or similar.
+    pub fn pentatonic_major(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &PENTATONIC_MAJOR)
+    }
+
+    /// Make a minor pentatonic generator (`[3, 2, 2, 3, 2]`) from a 12-TET [`FreqGenerator`] or similar.
+    pub fn pentatonic_minor(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &PENTATONIC_MINOR)
+    }
+
+    /// Make a harmonic minor generator (`[2, 1, 2, 2, 1, 3, 1]`) from a 12-TET [`FreqGenerator`] or similar.
+    pub fn harmonic_minor(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &HARMONIC_MINOR)
+    }
+
+    /// Make an ascending melodic minor generator (`[2, 1, 2, 2, 2, 2, 1]`) from a 12-TET [`FreqGenerator`] or similar.
+    ///
+    /// Classically, the descending form is the natural minor scale: use [`new`](Self::new) with [`Mode::F`] (aeolian) for it.
+    pub fn melodic_minor(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &MELODIC_MINOR)
+    }
+
+    /// Make a whole-tone generator (`[2, 2, 2, 2, 2, 2]`) from a 12-TET [`FreqGenerator`] or similar.
+    ///
+    /// Symmetric scales like this one repeat several times per octave, but the pattern still sums to 12
+    /// so the generator lands on the octave after six notes.
+    pub fn whole_tone(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &WHOLE_TONE)
+    }
+
+    /// Make a whole-half octatonic generator (`[2, 1, 2, 1, 2, 1, 2, 1]`) from a 12-TET [`FreqGenerator`] or similar.
+    /// The pattern sums to 12 so the generator lands on the octave after eight notes.
+    pub fn octatonic(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &OCTATONIC_WHOLE_HALF)
+    }
+
+    /// Make a half-whole octatonic generator (`[1, 2, 1, 2, 1, 2, 1, 2]`), the other rotation of [`octatonic`](Self::octatonic).
+    pub fn octatonic_half_whole(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &OCTATONIC_HALF_WHOLE)
+    }
+
+    /// Make a chromatic generator (`[1; 12]`) from a 12-TET [`FreqGenerator`] or similar. It yields the same notes
+    /// as the bare generator, but as a scale of 12 degrees for the degree-based methods like [`note`](Self::note).
+    pub fn chromatic(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &CHROMATIC)
+    }
+
+    /// Make a maqam rast generator (`[4, 3, 3, 4, 4, 3, 3]` in quarter tones) from a 24-TET [`FreqGenerator`] or similar,
+    /// see [`FreqGenerator::quarter_tone`]. Its 3rd and 7th degrees sit halfway between the major and minor ones.
+    pub fn rast(frequencies: I) -> Self {
+        Self::from_intervals(frequencies, &RAST)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I> ScaleGenerator<T, I>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    /// Collect the next octave (one full cycle of the interval pattern) of notes, without advancing the generator.
+    pub fn octave_vec(&self) -> alloc::vec::Vec<T> {
+        self.collect_octaves(1)
+    }
+
+    /// Collect the next `n` octaves (full cycles of the interval pattern) of notes, without advancing the generator.
+    /// Stops early if the underlying generator runs out of notes.
+    pub fn collect_octaves(&self, n: usize) -> alloc::vec::Vec<T> {
+        self.clone().take(n * self.intervals.len()).collect()
+    }
+}
+
+impl<T, I> ScaleGenerator<T, I>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Div<Output = T>
+        + Pow<T, Output = T>
+        + From<u8>
+        + MulAssign
+        + Pow<T>
+        + Copy
+        + Mul<Output = T>
+        + PartialOrd,
+{
+    /// Closest note (in cents, not in Hz) this generator would yield to the given frequency.
+    /// Works from a clone so the generator itself doesn't advance.
+    ///
+    /// Frequencies below the first note clamp to it and ties go to the lower note.
+    /// If the generator yields nothing, `freq` is returned as is.
+    pub fn snap(&self, freq: T) -> T {
+        let mut notes = self.clone();
+        let Some(mut lower) = notes.next() else {
+            return freq;
+        };
+        for upper in notes {
+            // anything but "below" stops, so a NaN input can't loop forever
+            if upper.partial_cmp(&freq) != Some(Ordering::Less) {
+                // comparing `freq / lower` with `upper / freq` without dividing
+                return if freq * freq <= lower * upper {
+                    lower
+                } else {
+                    upper
+                };
+            }
+            lower = upper;
+        }
+        lower
+    }
+
+    /// Same as [`snap`](Self::snap), also returning how far off the snapped note `freq` is, in cents.
+    /// The deviation is positive when `freq` is sharp.
+    pub fn snap_with_cents(&self, freq: T) -> (T, T)
+    where
+        T: Float,
+    {
+        let snapped = self.snap(freq);
+        (
+            snapped,
+            cast::<_, T>(1200).unwrap_or_else(T::nan) * (freq / snapped).log2(),
+        )
+    }
+}
+
+impl<T> ScaleGenerator<T>
+where
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Copy + FromPrimitive,
+{
+    /// Bring the generator back to the note and mode it was made with (or moved to by
+    /// [`skip_octaves`](Self::skip_octaves)), so it yields its first notes again.
+    /// Modulations are kept, see [`FreqGenerator::reset`].
+    pub fn reset(&mut self) {
+        self.fg.seek(self.fg.step - self.walked);
+        self.walked = 0;
+        self.phase = self.start;
+    }
+
+    /// Make a Bohlen–Pierce generator starting (exclusively) at `base`: the tritave (3:1) is divided in 13 equal steps
+    /// and the lambda mode (`[2, 1, 1, 2, 1, 2, 1, 2, 1]`) picks 9 of them.
+    #[must_use]
+    pub fn bohlen_pierce(base: T) -> Self {
+        Self::from_intervals(
+            FreqGenerator::with_ratio(base, 3.into(), 13.into()),
+            &BOHLEN_PIERCE_LAMBDA,
+        )
+    }
+}
+
+impl<T> ScaleGenerator<T>
+where
+    T: Float + Pow<T, Output = T> + From<u8> + MulAssign + FromPrimitive,
+{
+    /// Frequency of the `degree`th note above the current one, degree 0 being the current note itself
+    /// (the tonic as long as the generator hasn't been advanced).
+    fn degree(&self, degree: usize) -> T {
+        let steps = cast::<_, T>(self.steps_to(degree)).unwrap_or_else(T::nan);
+        self.fg.freq * Float::powf(self.fg.period, steps / self.fg.scale)
+    }
+
+    /// Frequency of the `degree`th note of the scale without iterating, degree 0 being the current note
+    /// (the tonic as long as the generator hasn't been advanced). Degrees past the scale length go up an octave,
+    /// so in a diatonic scale `note(8)` is an octave above `note(1)`.
+    pub fn note(&self, degree: usize) -> T {
+        self.degree(degree)
+    }
+
+    /// Tonic of the scale, the note the generator was made on: [`note(0)`](Self::note) until it advances
+    /// (moved along by [`modulate`](Self::modulate), [`transpose_to`](Self::transpose_to)
+    /// and [`skip_octaves`](Self::skip_octaves)). It stays the same as the generator advances.
+    pub fn tonic(&self) -> T {
+        let walked = cast::<_, T>(self.walked).unwrap_or_else(T::nan);
+        self.fg.freq * Float::powf(self.fg.period, -walked / self.fg.scale)
+    }
+
+    /// Change key mid-stream so the tonic becomes `target`, keeping the position in the interval pattern:
+    /// every future note goes up by the 0 to 11 semitones from the current tonic to `target`, see [`modulate`](Self::modulate).
+    ///
+    /// Only meaningful in 12-TET, like keys. A tonic off the 12-TET grid is rounded to the closest semitone first.
+    pub fn transpose_to(&mut self, target: Key)
+    where
+        T: From<f32>,
+    {
+        let target: T = target.to_freq();
+        let semitones = (cast::<_, T>(12).unwrap_or_else(T::nan) * (target / self.tonic()).log2())
+            .round()
+            .to_i32()
+            .unwrap_or_default();
+        self.modulate(semitones.rem_euclid(12));
+    }
+
+    /// Change key mid-stream: shift every future note by `semitones` steps of the underlying generator
+    /// (semitones in 12-TET), up or down, keeping the position in the interval pattern.
+    /// A running melody goes on in the new key without restarting.
+    pub fn modulate(&mut self, semitones: i32) {
+        self.fg.shift_steps(semitones);
+    }
+
+    /// Whether `freq` is one of the notes of the scale from the current note up (the tonic as long as the generator
+    /// hasn't been advanced), within `tolerance_cents` either way. Computed directly from the logarithm of `freq`
+    /// and the interval pattern, so it doesn't iterate.
+    ///
+    /// Frequencies below the current note (beyond the tolerance) are never contained.
+    pub fn contains(&self, freq: T, tolerance_cents: f32) -> bool
+    where
+        T: From<f32>,
+    {
+        let tolerance: T = tolerance_cents.into();
+        let step = self.step_cents();
+        let cents = self.cents_above(freq);
+        if cents < -tolerance {
+            return false;
+        }
+        let period = step * cast(self.octave_steps()).unwrap_or_else(T::nan);
+        let cents = cents - (cents / period).floor() * period;
+        // the last offset is the period itself, for notes slightly flat of the next octave
+        (0..=self.intervals.len()).any(|degree| {
+            let offset = step * cast(self.steps_to(degree)).unwrap_or_else(T::nan);
+            (cents - offset).abs() <= tolerance
+        })
+    }
+
+    /// Degree of `freq` in the scale (0 for the current note and its octaves, up to the scale length minus 1),
+    /// or `None` if it's not in the scale or below the current note.
+    ///
+    /// `freq` is first rounded to the closest step of the underlying generator (semitone in 12-TET),
+    /// see [`contains`](Self::contains) to check how close to the scale it actually is.
+    pub fn degree_of(&self, freq: T) -> Option<usize> {
+        let steps = (self.cents_above(freq) / self.step_cents())
+            .round()
+            .to_usize()?
+            % self.octave_steps();
+        (0..self.intervals.len()).find(|&degree| self.steps_to(degree) == steps)
+    }
+
+    /// Solfège syllable of the scale degree of `freq` in the current key (see [`solfege`](Self::solfege)),
+    /// or `None` if `freq` is more than `tolerance_cents` away from the scale (see [`contains`](Self::contains)).
+    /// In A minor, 440 Hz is `do`. See [`label_roman`](Self::label_roman) for the chord built on it instead.
+    pub fn label(&self, freq: T, tolerance_cents: f32) -> Option<&'static str>
+    where
+        T: From<f32>,
+    {
+        self.labelled_degree(freq, tolerance_cents)
+            .map(|degree| self.solfege(degree))
+    }
+
+    /// Roman numeral of the triad built on the scale degree of `freq` in the current key
+    /// (see [`roman_numeral`](Self::roman_numeral)), or `None` like [`label`](Self::label).
+    /// In A minor, 440 Hz is `i` and 523.25 Hz (C) is `III`.
+    pub fn label_roman(&self, freq: T, tolerance_cents: f32) -> Option<&'static str>
+    where
+        T: From<f32>,
+    {
+        self.labelled_degree(freq, tolerance_cents)
+            .and_then(|degree| self.roman_numeral(degree))
+    }
+
+    /// Scale degree of `freq` if it's within `tolerance_cents` of the scale.
+    fn labelled_degree(&self, freq: T, tolerance_cents: f32) -> Option<usize>
+    where
+        T: From<f32>,
+    {
+        if !self.contains(freq, tolerance_cents) {
+            return None;
+        }
+        self.degree_of(freq)
+    }
+
+    /// Closest note of the scale to `freq` (in cents, not in Hz), computed directly from the logarithm of `freq`
+    /// like [`contains`](Self::contains). Unlike [`snap`](Self::snap), the scale goes on below the current note.
+    ///
+    /// Ties go to the lower note. Frequencies with no note (zero, negative or not finite) are returned as they are.
+    fn nearest(&self, freq: T) -> T {
+        let steps = self.cents_above(freq) / self.step_cents();
+        if !steps.is_finite() {
+            return freq;
+        }
+        let period = cast::<_, T>(self.octave_steps()).unwrap_or_else(T::nan);
+        let octave = (steps / period).floor();
+        let within = steps - octave * period;
+        // offsets go up, so a tie keeps the lower one
+        let offset = (0..=self.intervals.len())
+            .map(|degree| cast::<_, T>(self.steps_to(degree)).unwrap_or_else(T::nan))
+            .reduce(|best, offset| {
+                if (within - best).abs() <= (within - offset).abs() {
+                    best
+                } else {
+                    offset
+                }
+            })
+            .unwrap_or_else(T::zero);
+        self.fg.freq * Float::powf(self.fg.period, (octave * period + offset) / self.fg.scale)
+    }
+
+    /// Size of a step of the underlying generator in cents.
+    fn step_cents(&self) -> T {
+        cast::<_, T>(1200).unwrap_or_else(T::nan) * self.fg.period.log2() / self.fg.scale
+    }
+
+    /// Distance from the current note to `freq` in cents.
+    fn cents_above(&self, freq: T) -> T {
+        cast::<_, T>(1200).unwrap_or_else(T::nan) * (freq / self.fg.freq).log2()
+    }
+
+    /// Chord built by stacking two thirds within the scale on `degree`: its 1st, 3rd and 5th degrees.
+    /// Degree 0 is the tonic, so in ionian `triad(0)` is the major chord of the key.
+    ///
+    /// The thirds follow the interval pattern, so they're major or minor depending on where they land in the scale.
+    #[must_use]
+    pub fn triad(&self, degree: usize) -> [T; 3] {
+        [0, 2, 4].map(|third| self.degree(degree + third))
+    }
+
+    /// Same as [`triad`](Self::triad) with one more third on top, giving the chord's 7th.
+    #[must_use]
+    pub fn seventh(&self, degree: usize) -> [T; 4] {
+        [0, 2, 4, 6].map(|third| self.degree(degree + third))
+    }
+
+    /// Same as [`triad`](Self::triad) in its `inversion`th inversion, see [`invert`]:
+    /// 1 has the 3rd in the bass and 2 the 5th, and 3 is the root position an octave higher.
+    #[must_use]
+    pub fn triad_inversion(&self, degree: usize, inversion: usize) -> [T; 3] {
+        let mut chord = self.triad(degree);
+        invert(&mut chord, inversion);
+        chord
+    }
+}
+
+impl<T> ScaleGenerator<T>
+where
+    T: Div<Output = T>
+        + Pow<T, Output = T>
+        + From<u8>
+        + MulAssign
+        + Pow<T>
+        + Copy
+        + From<f32>
+        + FromPrimitive,
+{
+    /// Make a 12-TET generator from a [`FreqGenerator`] using a key and a mode.
+    #[must_use]
+    pub fn from_key(key: Key, mode: Mode) -> Self {
+        Self::new(FreqGenerator::new(key.to_freq(), 12u8.into()), mode)
+    }
+}
+
+impl<T, I> Iterator for ScaleGenerator<T, I>
+where
+    I: Iterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        // `intervals` is never empty and `phase` stays below its length, this can't panic
+        let interval = self.intervals[self.phase];
+        self.phase = (self.phase + 1) % self.intervals.len();
+        self.walked += isize::from(interval);
+        for _ in 1..interval {
+            self.fg.next()?;
+        }
+        self.fg.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.fg.size_hint();
+        (
+            self.notes_within(lower),
+            upper.map(|upper| self.notes_within(upper)),
+        )
+    }
+}
+
+/// Walks the scale downward, stepping the interval pattern backward: each call moves down by the interval
+/// below the current note and yields the result, so a [`next`](Iterator::next) followed by a
+/// [`next_back`](DoubleEndedIterator::next_back) lands back on the starting note, like [`FreqGenerator`].
+impl<T, I> DoubleEndedIterator for ScaleGenerator<T, I>
+where
+    I: DoubleEndedIterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.phase = (self.phase + self.intervals.len() - 1) % self.intervals.len();
+        let interval = self.intervals[self.phase];
+        self.walked -= isize::from(interval);
+        for _ in 1..interval {
+            self.fg.next_back()?;
+        }
+        self.fg.next_back()
+    }
+}
+
+impl<T, I> FusedIterator for ScaleGenerator<T, I>
+where
+    I: FusedIterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+}
+
+impl<T, I> ExactSizeIterator for ScaleGenerator<T, I>
+where
+    I: ExactSizeIterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+}
+
+/// [`ScaleGenerator`] tracking octave numbers, see [`ScaleGenerator::with_octave`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithOctave<T = f32, I = FreqGenerator<T>>
+where
+    I: Iterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    scale: ScaleGenerator<T, I>,
+    /// Phase of the first note of each octave.
+    start: usize,
+    octave: i32,
+}
+
+impl<T, I> Iterator for WithOctave<T, I>
+where
+    I: Iterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+    type Item = (i32, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let freq = self.scale.next()?;
+        if self.scale.phase == self.start {
+            self.octave += 1;
+        }
+        Some((self.octave, freq))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.scale.size_hint()
+    }
+}
+
+impl<T, I> FusedIterator for WithOctave<T, I>
+where
+    I: FusedIterator<Item = T>,
+    T: Div<Output = T> + Pow<T, Output = T> + From<u8> + MulAssign + Pow<T> + Copy,
+{
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::float_cmp,
+    clippy::iter_skip_next,
+    clippy::cast_lossless,
+    clippy::semicolon_if_nothing_returned
+)]
+mod tests {
+    extern crate alloc;
+
+    use crate::Key;
+    use alloc::string::ToString;
+    use core::iter::FusedIterator;
+
+    use super::{
+        cents, Accidental, FreqGenerator, Mode, ParseKeyError, ParseModeError, ScaleGenerator, A0,
+        A4,
+    };
+    use num_traits::FromPrimitive;
+
+    #[test]
+    fn parse_mode() {
+        assert!(matches!("dorian".parse(), Ok(Mode::B)));
+        assert!(matches!("Aeolian".parse(), Ok(Mode::F)));
+        assert!(matches!("HYPOLYDIAN".parse(), Ok(Mode::C)));
+        assert_eq!(Err(ParseModeError), "doric".parse::<Mode>().map(|_| ()));
+    }
+
+    #[test]
+    fn display_mode() {
+        assert_eq!("Dorian", Mode::B.to_string());
+        assert_eq!("Hypolydian", Mode::C.medieval_name());
+        // named after the rotation from ionian, not the medieval name
+        assert_eq!("Lydian", Mode::D.to_string());
+        assert_eq!("Dorian", Mode::D.medieval_name());
+        assert_eq!(Ok(Mode::B), "dorian".parse());
+        for name in ["Ionian", "Mixolydian", "Locrian"] {
+            assert_eq!(name, name.parse::<Mode>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn all_modes() {
+        assert_eq!(7, Mode::all().count());
+        assert!(Mode::ALL
+            .windows(2)
+            .all(|w| w[0].to_skip() < w[1].to_skip()));
+    }
+
+    #[test]
+    fn mode_intervals() {
+        assert_eq!([2, 1, 2, 2, 2, 1, 2], Mode::B.intervals());
+        // D is lydian (the medieval dorian), see the variant docs
+        assert_eq!([2, 2, 2, 1, 2, 2, 1], Mode::D.intervals());
+        assert_eq!([2, 1, 2, 2, 1, 2, 2], Mode::AEOLIAN.intervals());
+        for mode in Mode::all() {
+            let mut semitones = 0;
+            for (step, freq) in mode
+                .intervals()
+                .into_iter()
+                .zip(ScaleGenerator::new(FreqGenerator::new(A0, 12f32), mode))
+            {
+                semitones += step;
+                assert_eq!(
+                    (A0 * 2f32.powf(f32::from(semitones) / 12.)).round(),
+                    freq.round()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_key() {
+        assert!(matches!(
+            "A#".parse(),
+            Ok(Key {
+                note: Mode::A,
+                accidental: Accidental::Sharp
+            })
+        ));
+        assert!(matches!(
+            "e".parse(),
+            Ok(Key {
+                note: Mode::E,
+                accidental: Accidental::Natural
+            })
+        ));
+        for garbage in ["", "H", "A#b", "C sharp"] {
+            assert_eq!(Err(ParseKeyError), garbage.parse::<Key>().map(|_| ()));
+        }
+    }
+
+    #[test]
+    fn accidentals() {
+        assert!(matches!(
+            "Bbb".parse(),
+            Ok(Key {
+                note: Mode::B,
+                accidental: Accidental::DoubleFlat
+            })
+        ));
+        assert_eq!(
+            Key::with_accidental(Mode::B, Accidental::Flat).to_freq::<f32>(),
+            Key::new(Mode::A, true).to_freq::<f32>()
+        );
+        assert_eq!(
+            Key::with_accidental(Mode::G, Accidental::DoubleSharp).to_freq::<f32>(),
+            Key::new(Mode::A, false).to_freq::<f32>() * 2.
+        );
+    }
+
+    #[test]
+    fn display_key() {
+        assert_eq!("E", Key::new(Mode::E, false).to_string());
+        assert_eq!("F#", Key::new(Mode::F, true).to_string());
+        assert_eq!(
+            "Ebb",
+            Key::with_accidental(Mode::E, Accidental::DoubleFlat).to_string()
+        );
+        for spelling in ["Bb", "C##", "G"] {
+            assert_eq!(spelling, spelling.parse::<Key>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(
+            440f32,
+            FreqGenerator::new(A0, 12f32)
+                .skip(12 * 4 - 1)
+                .next()
+                .unwrap()
+                .round()
+        );
+    }
+
+    #[test]
+    fn precision_f64() {
+        assert_eq!(
+            440f64,
+            FreqGenerator::new(A0 as f64, 12.0)
+                .skip(12 * 4 - 1)
+                .next()
+                .unwrap()
+                .round()
+        );
+    }
+
+    #[test]
+    fn nth_exact() {
+        assert_eq!(
+            440f32,
+            FreqGenerator::new(A0, 12f32).nth(12 * 4 - 1).unwrap()
+        );
+        assert_eq!(
+            A0 * 256.0,
+            FreqGenerator::new(A0, 12f32)
+                .skip(12 * 8 - 1)
+                .next()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn nth_then_next() {
+        let mut fg = FreqGenerator::new(A0, 12f32);
+        let mut stepped = FreqGenerator::new(440f32, 12f32);
+        fg.nth(12 * 4 - 1);
+        assert_eq!(stepped.next(), fg.next());
+        assert_eq!(None, fg.nth(usize::MAX));
+        assert_eq!(None, fg.nth(isize::MAX.unsigned_abs()));
+        assert_eq!(stepped.next(), fg.next());
+    }
+
+    #[test]
+    fn exact_octaves() {
+        for (freq, octave) in FreqGenerator::new(A0, 12f32)
+            .exact()
+            .skip(11)
+            .step_by(12)
+            .take(10)
+            .zip(1..)
+        {
+            assert_eq!(A0 * 2f32.powi(octave), freq);
+        }
+    }
+
+    #[test]
+    fn exact_descending() {
+        let mut fg = FreqGenerator::new(A0, 12f32).exact();
+        fg.nth(12 * 4 - 1);
+        assert_eq!(A0, fg.rev().nth(12 * 4 - 1).unwrap());
+    }
+
+    #[test]
+    fn tritave() {
+        assert_eq!(
+            3. * A0,
+            FreqGenerator::with_ratio(A0, 3f32, 13f32).nth(12).unwrap()
+        );
+        let mut exact = FreqGenerator::with_ratio(A0, 3f32, 13f32).exact();
+        assert_eq!(9. * A0, exact.nth(25).unwrap());
+    }
+
+    #[test]
+    fn up_to() {
+        let mut fg = FreqGenerator::new(A0, 12f32).up_to(440.);
+        assert_eq!(440f32, fg.by_ref().last().unwrap().round());
+        assert_eq!(None, fg.next());
+        assert_eq!(0, FreqGenerator::new(A0, 12f32).up_to(1.).count());
+        assert_eq!(
+            20000f32,
+            FreqGenerator::new(10000f32, 1f32).audible().last().unwrap()
+        );
+    }
+
+    #[test]
+    fn exact_size() {
+        let fg = FreqGenerator::new(A0, 12f32).up_to(440.);
+        assert_eq!(48, fg.len());
+        assert_eq!(fg.len(), fg.count());
+        for mode in Mode::all() {
+            let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32).up_to(1000.), mode);
+            assert_eq!(scale.len(), scale.count());
+        }
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32).up_to(440.), Mode::A);
+        assert_eq!(28, scale.len());
+        let scale = ScaleGenerator::pentatonic_minor(FreqGenerator::new(A0, 12f32).up_to(56.));
+        assert_eq!(5, scale.len());
+    }
+
+    #[test]
+    fn fused() {
+        fn fused<T>(_: impl FusedIterator<Item = T>) {}
+        fused(FreqGenerator::new(A0, 12f32));
+        fused(FreqGenerator::new(A0, 12f32).up_to(440.));
+        fused(ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::A));
+        fused(ScaleGenerator::new(
+            FreqGenerator::new(A0, 12f32).up_to(440.),
+            Mode::A,
+        ));
+    }
+
+    #[test]
+    fn fill() {
+        let mut fg = FreqGenerator::new(A0, 12f32);
+        let mut reference = fg.clone();
+        let mut buf = [0f32; 64];
+        assert_eq!(64, fg.fill(&mut buf));
+        assert!(buf.iter().all(|&freq| Some(freq) == reference.next()));
+        assert_eq!(reference.next(), fg.next());
+        let mut bounded = FreqGenerator::new(A0, 12f32).up_to(55.);
+        assert_eq!(12, bounded.fill(&mut buf));
+        assert_eq!(0, bounded.fill(&mut buf));
+    }
+
+    #[test]
+    fn descending() {
+        let a0 = FreqGenerator::new(440f32, 12f32)
+            .rev()
+            .nth(12 * 4 - 1)
+            .unwrap();
+        assert!((a0 - A0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut fg = FreqGenerator::new(A0, 12f32);
+        let up = fg.next().unwrap();
+        assert!((fg.next_back().unwrap() - A0).abs() < 1e-4);
+        assert_eq!(up, fg.next().unwrap());
+    }
+
+    #[test]
+    fn snap() {
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::A);
+        assert_eq!(440f32, scale.snap(450.).round());
+        assert_eq!(494f32, scale.snap(480.).round());
+        assert_eq!(31f32, scale.snap(1.).round());
+        let (snapped, cents) = scale.snap_with_cents(445.);
+        assert_eq!(440f32, snapped.round());
+        assert_eq!(20f32, cents.round());
+    }
+
+    #[test]
+    fn snap_tie() {
+        // yields 4, 16, 32...
+        let scale = ScaleGenerator::new(FreqGenerator::new(1f32, 1f32), Mode::A);
+        assert_eq!(4f32, scale.snap(8.));
+    }
+
+    #[test]
+    fn harmonic_minor() {
+        let mut scale =
+            ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32), &[2, 1, 2, 2, 1, 3, 1]);
+        assert_eq!(
+            [31f32, 33., 37., 41., 44., 52., 55.],
+            core::array::from_fn(|_| scale.next().unwrap().round())
+        );
+    }
+
+    #[test]
+    fn pentatonic() {
+        let mut major = ScaleGenerator::pentatonic_major(FreqGenerator::new(A0, 12f32));
+        assert_eq!(
+            [31f32, 35., 41., 46., 55.],
+            core::array::from_fn(|_| major.next().unwrap().round())
+        );
+        let mut minor = ScaleGenerator::pentatonic_minor(FreqGenerator::new(A0, 12f32));
+        assert_eq!(
+            [33f32, 37., 41., 49., 55.],
+            core::array::from_fn(|_| minor.next().unwrap().round())
+        );
+    }
+
+    #[test]
+    fn minor_variants() {
+        // F natural then G# in harmonic minor, F# then G# in melodic minor
+        let mut harmonic = ScaleGenerator::harmonic_minor(FreqGenerator::new(A0, 12f32)).skip(4);
+        assert_eq!(44f32, harmonic.next().unwrap().round());
+        assert_eq!(52f32, harmonic.next().unwrap().round());
+        let mut melodic = ScaleGenerator::melodic_minor(FreqGenerator::new(A0, 12f32)).skip(4);
+        assert_eq!(46f32, melodic.next().unwrap().round());
+        assert_eq!(52f32, melodic.next().unwrap().round());
+    }
+
+    #[test]
+    fn whole_tone() {
+        let mut scale = ScaleGenerator::whole_tone(FreqGenerator::new(A0, 12f32));
+        assert_eq!(
+            [31f32, 35., 39., 44., 49., 55.],
+            core::array::from_fn(|_| scale.next().unwrap().round())
+        );
+    }
+
+    #[test]
+    fn octatonic() {
+        assert_eq!(
+            55f32,
+            ScaleGenerator::octatonic(FreqGenerator::new(A0, 12f32))
+                .nth(7)
+                .unwrap()
+                .round()
+        );
+        assert_eq!(
+            29f32,
+            ScaleGenerator::octatonic_half_whole(FreqGenerator::new(A0, 12f32))
+                .next()
+                .unwrap()
+                .round()
+        );
+    }
+
+    #[test]
+    #[should_panic = "empty interval pattern"]
+    fn empty_intervals() {
+        ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32), &[]);
+    }
+
+    #[test]
+    #[should_panic = "zero interval in pattern"]
+    fn zero_interval() {
+        ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32), &[0, 2, 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn octave_vec() {
+        let scale = ScaleGenerator::pentatonic_major(FreqGenerator::new(A0, 12f32));
+        assert_eq!(
+            alloc::vec![31f32, 35., 41., 46., 55.],
+            scale
+                .octave_vec()
+                .into_iter()
+                .map(f32::round)
+                .collect::<alloc::vec::Vec<_>>()
+        );
+        let octaves =
+            ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::A).collect_octaves(4);
+        assert_eq!(28, octaves.len());
+        assert_eq!(440f32, octaves[27].round());
+    }
+
+    #[test]
+    fn scale_octave() {
+        assert_eq!(
+            440f32,
+            ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::A)
+                .skip(4 * 7 - 1)
+                .next()
+                .unwrap()
+                .round()
+        )
+    }
+
+    #[test]
+    fn major() {
+        assert_eq!(
+            554f32, // C#, part of A chord
+            ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::from_usize(0).unwrap())
+                .skip(4 * 7 - 1 + 2)
+                .next()
+                .unwrap()
+                .round()
+        )
+    }
+
+    #[test]
     fn minor() {
         assert_eq!(
             523f32, // C#, part of A chord
@@ -235,10 +2033,633 @@ mod tests {
         assert_eq!(
             740f32, // F#, part of E chord
             ScaleGenerator::<f32>::from_key(Key::new(Mode::E, false), Mode::default())
-                .skip(4 * 7 + 1)
+                .skip(4 * 7)
                 .next()
                 .unwrap()
                 .round()
         )
     }
+
+    #[test]
+    fn triad() {
+        let c_ionian = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::A);
+        let [root, third, fifth] = c_ionian.triad(0);
+        assert!((third / root - 2f32.powf(4. / 12.)).abs() < 1e-4);
+        assert!((fifth / root - 2f32.powf(7. / 12.)).abs() < 1e-4);
+        // D minor on the 2nd degree
+        let [root, third, _] = c_ionian.triad(1);
+        assert!((third / root - 2f32.powf(3. / 12.)).abs() < 1e-4);
+        // B diminished on the 7th, with its 5th on the next octave's F
+        let [root, _, fifth] = c_ionian.triad(6);
+        assert!((fifth / root - 2f32.powf(6. / 12.)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn getters() {
+        let mut generator = FreqGenerator::inclusive(A0, 19f32);
+        generator.nth(30);
+        assert_eq!(A0, generator.base());
+        assert_eq!(19., generator.scale());
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::DORIAN);
+        assert_eq!(&[2, 2, 1, 2, 2, 2, 1], scale.intervals_slice());
+        assert_eq!(
+            &[2, 2, 3, 2, 3],
+            ScaleGenerator::pentatonic_major(generator).intervals_slice()
+        );
+    }
+
+    #[test]
+    fn label() {
+        let a_minor = ScaleGenerator::<f32>::from_key(Key::new(Mode::A, false), Mode::AEOLIAN);
+        assert_eq!(Some("do"), a_minor.label(440., 5.));
+        assert_eq!(Some("me"), a_minor.label(523.25, 5.));
+        assert_eq!(Some("sol"), a_minor.label(660., 5.));
+        assert_eq!(None, a_minor.label(466., 5.));
+        assert_eq!(None, a_minor.label(452., 5.));
+        assert_eq!(Some("do"), a_minor.label(446., 30.));
+        assert_eq!(Some("i"), a_minor.label_roman(440., 5.));
+        assert_eq!(Some("ii°"), a_minor.label_roman(493.88, 5.));
+        assert_eq!(Some("III"), a_minor.label_roman(523.25, 5.));
+        assert_eq!(Some("v"), a_minor.label_roman(660., 5.));
+        assert_eq!(None, a_minor.label_roman(466., 5.));
+    }
+
+    #[test]
+    fn triad_inversion() {
+        let c_major = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::IONIAN);
+        let [c, e, g] = c_major.triad(0);
+        let first = c_major.triad_inversion(0, 1);
+        assert_eq!([e, g, c * 2.], first);
+        assert!(first.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!([g, c * 2., e * 2.], c_major.triad_inversion(0, 2));
+        assert_eq!([c * 2., e * 2., g * 2.], c_major.triad_inversion(0, 3));
+    }
+
+    #[test]
+    fn transpose_to() {
+        let c: Key = "C".parse().unwrap();
+        let g: Key = "G".parse().unwrap();
+        let c_major = ScaleGenerator::<f32>::from_key(c, Mode::IONIAN);
+        let mut g_major = c_major.clone();
+        g_major.transpose_to(g);
+        assert!((g_major.tonic() / c_major.tonic() - 2f32.powf(7. / 12.)).abs() < 1e-5);
+        for (c, g) in c_major.clone().zip(g_major.clone()).take(15) {
+            assert!((g / c - 2f32.powf(7. / 12.)).abs() < 1e-5);
+        }
+        // back up to the next C
+        g_major.transpose_to(c);
+        assert!((g_major.tonic() / c_major.tonic() - 2.).abs() < 1e-5);
+        g_major.transpose_to(c);
+        assert!((g_major.tonic() / c_major.tonic() - 2.).abs() < 1e-5);
+
+        // the tonic is the note the scale is on, whether the generator was skipped up or made inclusive
+        let a = FreqGenerator::new(A0, 12f32);
+        for scale in [
+            ScaleGenerator::new(a.clone().skip_octaves(4), Mode::IONIAN),
+            ScaleGenerator::new(a.clone(), Mode::IONIAN).skip_octaves(4),
+            ScaleGenerator::new(FreqGenerator::inclusive(A0, 12f32), Mode::IONIAN),
+        ] {
+            assert_eq!(scale.note(0), scale.tonic());
+            let mut g_major = scale.clone();
+            g_major.transpose_to(g);
+            let semitones = 12. * (g_major.tonic() / g.to_freq::<f32>()).log2();
+            assert!((semitones - semitones.round()).abs() < 1e-3);
+            assert_eq!(0., semitones.round() % 12.);
+        }
+        let mut walking = ScaleGenerator::new(a, Mode::IONIAN);
+        walking.nth(9);
+        walking.next_back();
+        assert!((walking.tonic() / A0 - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn non_octave_pattern() {
+        // sums to 13 semitones, a minor ninth
+        let scale =
+            ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32), &[2, 2, 3, 2, 2, 2]);
+        assert_eq!(13, scale.octave_steps());
+        let ratio = 2f32.powf(13. / 12.);
+        for degree in 0..12 {
+            assert!(
+                (scale.note(degree + scale.scale_len()) / scale.note(degree) - ratio).abs() < 1e-5
+            );
+            assert!(scale.contains(scale.note(degree), 1.));
+            assert_eq!(Some(degree % 6), scale.degree_of(scale.note(degree)));
+        }
+        assert!(!scale.contains(A0 * 2., 1.));
+        assert!(scale
+            .clone()
+            .take(12)
+            .zip(1..)
+            .all(|(freq, degree)| (scale.note(degree) / freq - 1.).abs() < 1e-5));
+    }
+
+    #[test]
+    fn runtime_intervals() {
+        // the pattern doesn't outlive the block
+        let mut scale = {
+            let intervals: [u8; 3] = core::array::from_fn(|i| u8::try_from(i).unwrap() + 3);
+            ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32).exact(), &intervals)
+        };
+        assert_eq!(&[3, 4, 5], scale.intervals_slice());
+        assert_eq!(A0 * 2f32.powf(7. / 12.), scale.nth(1).unwrap());
+        assert_eq!(A0 * 2., scale.next().unwrap());
+        let long = [1; super::MAX_INTERVALS];
+        assert_eq!(
+            super::MAX_INTERVALS,
+            ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32), &long).scale_len()
+        );
+    }
+
+    #[test]
+    #[should_panic = "interval pattern longer than MAX_INTERVALS"]
+    fn long_intervals() {
+        ScaleGenerator::from_intervals(
+            FreqGenerator::new(A0, 12f32),
+            &[1; super::MAX_INTERVALS + 1],
+        );
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(Mode::AEOLIAN, Mode::IONIAN.rotate(5));
+        assert_eq!(Mode::LOCRIAN, Mode::IONIAN.rotate(-1));
+        assert_eq!(Mode::DORIAN, Mode::LOCRIAN.rotate(2));
+        assert_eq!(Mode::LYDIAN, Mode::LYDIAN.rotate(-14));
+        for mode in Mode::all() {
+            assert_eq!(mode.rotate(1).intervals(), {
+                let mut intervals = mode.intervals();
+                intervals.rotate_left(1);
+                intervals
+            });
+        }
+    }
+
+    #[test]
+    fn chromatic() {
+        let chromatic = ScaleGenerator::chromatic(FreqGenerator::new(A0, 12f32));
+        assert_eq!(12, chromatic.scale_len());
+        assert_eq!(A0 * 2., chromatic.note(12));
+        assert_eq!(Some(3), chromatic.degree_of(A0 * 2f32.powf(3. / 12.)));
+        assert!(chromatic
+            .zip(FreqGenerator::new(A0, 12f32))
+            .take(30)
+            .all(|(a, b)| (a / b - 1.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn note() {
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::DORIAN);
+        assert_eq!(A0, scale.note(0));
+        assert_eq!(scale.note(0) * 2., scale.note(7));
+        assert!((scale.note(8) / scale.note(1) - 2.).abs() < 1e-6);
+        assert!(scale
+            .clone()
+            .take(10)
+            .zip(1..)
+            .all(|(freq, degree)| (scale.note(degree) / freq - 1.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn scale_down() {
+        let c = "C".parse::<Key>().unwrap();
+        let tonic: f32 = c.to_freq();
+        let mut scale = ScaleGenerator::from_key(c, Mode::IONIAN);
+        let up: [f32; 7] = core::array::from_fn(|_| scale.next().unwrap());
+        let down: [f32; 7] = core::array::from_fn(|_| scale.next_back().unwrap());
+        // C major down from C5: B A G F E D, then back to C4
+        for (down, up) in down.iter().zip(up.iter().rev().skip(1)) {
+            assert!((down / up - 1.).abs() < 1e-5);
+        }
+        assert!((down[6] / tonic - 1.).abs() < 1e-5);
+        // F down to E is a half step, D down to C a whole step
+        assert!((down[4] / down[3] - 2f32.powf(-1. / 12.)).abs() < 1e-5);
+        assert!((down[6] / down[5] - 2f32.powf(-2. / 12.)).abs() < 1e-5);
+        let mut descending = ScaleGenerator::<f32>::from_key(c, Mode::IONIAN).rev();
+        assert!((descending.next().unwrap() / tonic - 2f32.powf(-1. / 12.)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn reset() {
+        let mut generator = FreqGenerator::inclusive(A0, 12f32);
+        generator.nth(47);
+        generator.reset();
+        assert_eq!(generator.next(), FreqGenerator::inclusive(A0, 12f32).next());
+
+        let mut scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::DORIAN);
+        scale.nth(47);
+        scale.reset();
+        let fresh = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::DORIAN);
+        assert!(scale.take(8).eq(fresh.take(8)));
+
+        // made on a generator four octaves up, or moved there
+        let skipped = [
+            ScaleGenerator::new(FreqGenerator::new(A0, 12f32).skip_octaves(4), Mode::AEOLIAN),
+            ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::AEOLIAN).skip_octaves(4),
+        ];
+        for mut scale in skipped {
+            let first: [f32; 10] = core::array::from_fn(|_| scale.next().unwrap());
+            assert_eq!(494f32, first[0].round());
+            scale.nth(20);
+            scale.next_back();
+            scale.reset();
+            assert!(scale
+                .zip(first)
+                .all(|(freq, expected)| (freq / expected - 1.).abs() < 1e-6));
+        }
+    }
+
+    #[test]
+    fn modulate() {
+        let mut scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN);
+        scale.nth(2);
+        let mut modulated = scale.clone();
+        modulated.modulate(2);
+        for (freq, shifted) in scale.zip(modulated).take(15) {
+            assert!((shifted / freq - 2f32.powf(2. / 12.)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn contains() {
+        let a_minor = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::AEOLIAN);
+        assert!(a_minor.contains(440., 5.));
+        assert!(!a_minor.contains(445., 5.));
+        assert!(a_minor.contains(445., 20.));
+        // C4 and G#4
+        assert!(a_minor.contains(261.63, 1.));
+        assert!(!a_minor.contains(415.3, 1.));
+        // slightly flat of the tonic
+        assert!(a_minor.contains(A0 * 0.999, 5.));
+        assert!(!a_minor.contains(A0 / 2., 5.));
+        assert!(!a_minor.contains(f32::NAN, 5.));
+    }
+
+    #[test]
+    fn degree_of() {
+        let c_major = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::IONIAN);
+        assert_eq!(Some(4), c_major.degree_of(392.));
+        assert_eq!(Some(0), c_major.degree_of(523.25));
+        assert_eq!(Some(6), c_major.degree_of(493.88));
+        assert_eq!(None, c_major.degree_of(415.3));
+        assert_eq!(None, c_major.degree_of(A0));
+        assert_eq!(None, c_major.degree_of(f32::NAN));
+    }
+
+    #[test]
+    fn scale_len() {
+        let diatonic = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::default());
+        assert_eq!((7, 12), (diatonic.scale_len(), diatonic.period_semitones()));
+        let pentatonic = ScaleGenerator::pentatonic_minor(FreqGenerator::new(A0, 12f32));
+        assert_eq!(
+            (5, 12),
+            (pentatonic.scale_len(), pentatonic.period_semitones())
+        );
+        let octave = pentatonic
+            .clone()
+            .take(pentatonic.scale_len())
+            .last()
+            .unwrap();
+        assert_eq!(55f32, octave.round());
+        let lambda = ScaleGenerator::bohlen_pierce(A0);
+        assert_eq!((9, 13), (lambda.scale_len(), lambda.period_semitones()));
+    }
+
+    #[test]
+    fn roman_numerals() {
+        let ionian = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN);
+        let numerals: [&str; 7] =
+            core::array::from_fn(|degree| ionian.roman_numeral(degree).unwrap());
+        assert_eq!(["I", "ii", "iii", "IV", "V", "vi", "vii°"], numerals);
+        assert_eq!(Some("I"), ionian.roman_numeral(7));
+        let aeolian = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::AEOLIAN);
+        assert_eq!(Some("i"), aeolian.roman_numeral(0));
+        assert_eq!(Some("ii°"), aeolian.roman_numeral(1));
+        let harmonic = ScaleGenerator::harmonic_minor(FreqGenerator::new(A0, 12f32));
+        assert_eq!(Some("III+"), harmonic.roman_numeral(2));
+        assert_eq!(Some("V"), harmonic.roman_numeral(4));
+        // past the table: the 8th degree of the octatonic scale has a numeral, the 9th of the chromatic one doesn't
+        let octatonic = ScaleGenerator::octatonic(FreqGenerator::new(A0, 12f32));
+        assert_eq!(Some("viii°"), octatonic.roman_numeral(7));
+        let chromatic = ScaleGenerator::chromatic(FreqGenerator::new(A0, 12f32));
+        assert!(chromatic.roman_numeral(7).is_some());
+        assert!((8..12).all(|degree| chromatic.roman_numeral(degree).is_none()));
+        assert!(chromatic.roman_numeral(12).is_some());
+    }
+
+    #[test]
+    fn solfege() {
+        let major = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN);
+        assert_eq!("sol", major.solfege(4));
+        let syllables: [&str; 8] = core::array::from_fn(|degree| major.solfege(degree));
+        assert_eq!(["do", "re", "mi", "fa", "sol", "la", "ti", "do"], syllables);
+        let harmonic = ScaleGenerator::harmonic_minor(FreqGenerator::new(A0, 12f32));
+        let syllables: [&str; 7] = core::array::from_fn(|degree| harmonic.solfege(degree));
+        assert_eq!(["do", "re", "me", "fa", "sol", "le", "ti"], syllables);
+    }
+
+    #[test]
+    fn seventh() {
+        let c_ionian = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::A);
+        // G7
+        let [root, _, _, seventh] = c_ionian.seventh(4);
+        assert!((seventh / root - 2f32.powf(10. / 12.)).abs() < 1e-4);
+        assert_eq!(c_ionian.seventh(0)[..3], c_ionian.triad(0));
+    }
+
+    #[test]
+    fn with_octave() {
+        // C1, right above A0
+        let c_ionian = ScaleGenerator::<f32>::from_key(Key::new(Mode::C, false), Mode::A);
+        let mut notes = c_ionian.with_octave();
+        for _ in 0..6 {
+            assert_eq!(0, notes.next().unwrap().0);
+        }
+        let (octave, c2) = notes.next().unwrap();
+        assert_eq!(1, octave);
+        assert_eq!(65f32, c2.round());
+        assert_eq!(1, notes.nth(5).unwrap().0);
+        assert_eq!(2, notes.next().unwrap().0);
+    }
+
+    #[test]
+    fn transpose() {
+        let a4 = FreqGenerator::from_midi(69, 12f32);
+        assert_eq!(220f32, a4.clone().transpose(-12).next().unwrap().round());
+        assert_eq!(659f32, a4.clone().transpose(7).next().unwrap().round());
+        let mut exact = a4.exact().transpose(-12);
+        assert_eq!(220f32, exact.next().unwrap().round());
+        assert_eq!(233f32, exact.next().unwrap().round());
+    }
+
+    #[test]
+    fn at() {
+        let fg = FreqGenerator::new(A0, 12f32);
+        assert!((fg.at(47.) / fg.clone().skip(47).next().unwrap() - 1.).abs() < 1e-6);
+        let (lower, upper) = (fg.clone().nth(11).unwrap(), fg.clone().nth(12).unwrap());
+        let middle = fg.at(11.5);
+        assert!(lower < middle && middle < upper);
+        assert!((middle * middle / (lower * upper) - 1.).abs() < 1e-6);
+        let mut advanced = fg.clone();
+        advanced.nth(9);
+        assert!((advanced.at(1.5) / fg.at(11.5) - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn detune() {
+        let a4 = FreqGenerator::from_midi(69, 12f32);
+        let octave = a4.clone().detune(1200.).next().unwrap();
+        assert!((octave - a4.clone().transpose(12).next().unwrap()).abs() < 1e-3);
+        assert_eq!(880f32, octave.round());
+        let detuned = a4.clone().transpose(-12).detune(-15.).next().unwrap();
+        assert!((cents(220., detuned) + 15.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn circle_of_fifths() {
+        let c = Key::new(Mode::C, false);
+        let keys: [Key; 13] = {
+            let mut circle = super::circle_of_fifths(c);
+            core::array::from_fn(|_| circle.next().unwrap())
+        };
+        assert_eq!(
+            ["C", "G", "D", "A", "E", "B", "F#", "C#", "G#", "D#", "A#", "E#", "C"],
+            keys.map(|key| key.to_string())
+        );
+        let mut flats = super::circle_of_fifths("Gb".parse().unwrap()).skip(1);
+        assert_eq!("Db", flats.next().unwrap().to_string());
+        // a fifth above B## would be F###
+        let mut sharps = super::circle_of_fifths("B##".parse().unwrap()).skip(1);
+        assert_eq!("G#", sharps.next().unwrap().to_string());
+    }
+
+    #[test]
+    fn inclusive() {
+        let mut fg = FreqGenerator::inclusive(A0, 12f32);
+        assert_eq!(A0, fg.next().unwrap());
+        assert_eq!(
+            440f32,
+            FreqGenerator::inclusive(A0, 12f32)
+                .skip(48)
+                .next()
+                .unwrap()
+                .round()
+        );
+        assert_eq!(
+            440f32,
+            FreqGenerator::new(A0, 12f32)
+                .skip(47)
+                .next()
+                .unwrap()
+                .round()
+        );
+        fg.next();
+        assert_eq!(A0, fg.next_back().unwrap());
+
+        // the scale is built on the step below: B major for an inclusive C
+        let c: f32 = "C".parse::<Key>().unwrap().to_freq();
+        let inclusive = FreqGenerator::inclusive(c, 12.);
+        let b = ScaleGenerator::new(FreqGenerator::new(inclusive.current(), 12.), Mode::IONIAN);
+        let scale = ScaleGenerator::new(inclusive, Mode::IONIAN);
+        assert_eq!(b.note(0), scale.note(0));
+        assert!(scale
+            .take(15)
+            .zip(b)
+            .all(|(freq, expected)| (freq / expected - 1.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn quarter_tone() {
+        let mut fg = FreqGenerator::quarter_tone(A4);
+        let quarters: [f32; 24] = core::array::from_fn(|_| fg.next().unwrap());
+        assert_eq!(880f32, quarters[23].round());
+        assert_eq!(
+            FreqGenerator::new(A4, 12f32).next().unwrap().round(),
+            quarters[1].round()
+        );
+        let mut rast = ScaleGenerator::rast(FreqGenerator::quarter_tone(A4));
+        let octave: [f32; 7] = core::array::from_fn(|_| rast.next().unwrap());
+        assert_eq!(880f32, octave[6].round());
+        // neutral third, 350 cents
+        assert_eq!(350f32, cents(A4, octave[1]).round());
+    }
+
+    #[test]
+    fn bohlen_pierce() {
+        let mut chroma = FreqGenerator::with_ratio(A4, 3., 13.);
+        assert_eq!(1320f32, chroma.nth(12).unwrap().round());
+        let mut lambda = ScaleGenerator::bohlen_pierce(A4);
+        let tritave: [f32; 9] = core::array::from_fn(|_| lambda.next().unwrap());
+        assert!((tritave[8] / A4 - 3.).abs() < 1e-4);
+        assert!(tritave.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn skip_octaves() {
+        let mut fg = FreqGenerator::new(A0, 12f32).skip_octaves(4);
+        assert_eq!(
+            FreqGenerator::new(A4, 12f32).next().unwrap().round(),
+            fg.next().unwrap().round()
+        );
+        let mut inclusive = FreqGenerator::inclusive(A0, 12f32).skip_octaves(4);
+        assert_eq!(A4, inclusive.next().unwrap().round());
+        // way past the range of `f32`, but the step count doesn't overflow
+        let mut far = FreqGenerator::new(A0, 12f32).skip_octaves(u32::MAX);
+        assert_eq!(Some(f32::INFINITY), far.next());
+        let mut far = ScaleGenerator::from_intervals(FreqGenerator::new(A0, 12f32), &[255; 32])
+            .skip_octaves(u32::MAX);
+        assert_eq!(Some(f32::INFINITY), far.next());
+        let tritave = FreqGenerator::with_ratio(A0, 3., 13.).skip_octaves(1);
+        assert!((tritave.cents_from(A0) - cents(1., 3.)).abs() < 1e-2);
+        assert_eq!(
+            FreqGenerator::new(A0, 12f32).next(),
+            FreqGenerator::new(A0, 12f32).skip_octaves(0).next()
+        );
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::AEOLIAN);
+        assert_eq!(
+            scale.clone().skip(4 * 7 - 1).next().unwrap().round(),
+            scale.skip_octaves(4).nth(6).unwrap().round() / 2.
+        );
+    }
+
+    #[test]
+    fn current() {
+        let mut fg = FreqGenerator::new(A0, 12f32);
+        assert_eq!(A0, fg.current());
+        let next = fg.next().unwrap();
+        assert_eq!(next, fg.current());
+        let skipped = fg.nth(46).unwrap();
+        assert_eq!(skipped, fg.current());
+        assert_eq!(440f32, fg.current().round());
+        let back = fg.next_back().unwrap();
+        assert_eq!(back, fg.current());
+    }
+
+    #[test]
+    fn default() {
+        let mut fg = FreqGenerator::default();
+        for octave in [2., 4., 8., 16.] {
+            assert_eq!(A0 * octave, fg.nth(11).unwrap().round());
+        }
+    }
+
+    #[test]
+    fn from_reference() {
+        let mut fg = FreqGenerator::from_reference(432., 12f32);
+        assert_eq!(432f32, fg.nth(47).unwrap().round());
+        assert_eq!(
+            FreqGenerator::new(A0, 12f32).nth(47),
+            FreqGenerator::from_reference(A4, 12f32).nth(47)
+        );
+    }
+
+    #[test]
+    fn aeolian() {
+        let mut minor = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::AEOLIAN);
+        let semitones: [f32; 7] =
+            core::array::from_fn(|_| (12. * (minor.next().unwrap() / A0).log2()).round());
+        assert_eq!([2., 3., 5., 7., 8., 10., 12.], semitones);
+        assert_eq!("Locrian", Mode::LOCRIAN.to_string());
+        assert_eq!(Mode::AEOLIAN.intervals(), [2, 1, 2, 2, 1, 2, 2]);
+    }
+
+    #[test]
+    fn every_mode() {
+        for mode in Mode::ALL {
+            let mut scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), mode);
+            let octave: [f32; 7] = core::array::from_fn(|_| scale.next().unwrap());
+            assert_eq!(55f32, octave[6].round());
+        }
+    }
+
+    #[test]
+    fn derives() {
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::DORIAN);
+        let mut copy = scale.clone();
+        assert_eq!(scale, copy);
+        let original: [f32; 20] = {
+            let mut scale = scale;
+            core::array::from_fn(|_| scale.next().unwrap())
+        };
+        let copied: [f32; 20] = core::array::from_fn(|_| copy.next().unwrap());
+        assert_eq!(original, copied);
+        assert_eq!(Mode::B, Mode::DORIAN);
+        assert_eq!("E".parse::<Key>(), Ok(Key::new(Mode::E, false)));
+        assert_ne!(Key::new(Mode::C, true), "Db".parse().unwrap());
+        assert_eq!(
+            "Key { note: A, accidental: Sharp }",
+            alloc::format!("{:?}", Key::new(Mode::A, true))
+        );
+    }
+
+    #[test]
+    fn key_into_iter() {
+        let key = Key::new(Mode::G, false);
+        let mut scale = ScaleGenerator::<f32>::from_key(key, Mode::default());
+        for (freq, expected) in key.into_iter().zip(scale.by_ref()).take(10) {
+            assert_eq!(expected, freq);
+        }
+        let mut notes = 0;
+        for freq in key {
+            if freq > 441. {
+                break;
+            }
+            notes += 1;
+        }
+        // above G1 up to A4
+        assert_eq!(22, notes);
+    }
+
+    #[test]
+    fn enharmonic() {
+        let c_sharp = Key::new(Mode::C, true);
+        let d_flat: Key = "Db".parse().unwrap();
+        assert!(c_sharp.is_enharmonic_with(d_flat));
+        assert_eq!(c_sharp.to_freq::<f32>(), d_flat.to_freq::<f32>());
+        assert_eq!(d_flat, c_sharp.enharmonic());
+        assert_eq!(c_sharp, d_flat.enharmonic());
+        for (key, enharmonic) in [
+            ("A#", "Bb"),
+            ("B", "Cb"),
+            ("C", "B#"),
+            ("E", "Fb"),
+            ("F", "E#"),
+            ("C##", "D"),
+            ("Ebb", "D"),
+            ("G", "G"),
+        ] {
+            let key: Key = key.parse().unwrap();
+            assert_eq!(enharmonic, key.enharmonic().to_string());
+            assert!(key.is_enharmonic_with(key.enharmonic()));
+        }
+        assert!(!c_sharp.is_enharmonic_with(Key::new(Mode::D, false)));
+    }
+
+    #[test]
+    fn signature() {
+        let key = |key: &str| key.parse::<Key>().unwrap();
+        assert_eq!(0, key("C").signature());
+        assert_eq!(1, key("G").signature());
+        assert_eq!(-1, key("F").signature());
+        assert_eq!(7, key("C#").signature());
+        assert_eq!(-5, key("Db").signature());
+        assert_eq!(0, key("A").signature_in(Mode::AEOLIAN));
+        assert_eq!(2, key("E").signature_in(Mode::DORIAN));
+        assert_eq!(-1, key("D").signature_in(Mode::AEOLIAN));
+        for (key, fifths) in super::circle_of_fifths(key("C")).zip(0..7) {
+            assert_eq!(fifths, key.signature());
+        }
+    }
+
+    #[test]
+    fn relative() {
+        let key = |key: &str| key.parse::<Key>().unwrap();
+        assert_eq!((key("A"), Mode::AEOLIAN), key("C").relative_minor());
+        assert_eq!((key("C"), Mode::IONIAN), key("A").relative_major());
+        assert_eq!(key("D#"), key("F#").relative_minor().0);
+        assert_eq!(key("C"), key("Eb").relative_minor().0);
+        assert_eq!(key("Bb"), key("G").relative_major().0);
+        let (minor, mode) = key("G").relative_minor();
+        assert_eq!(key("G").signature(), minor.signature_in(mode));
+    }
 }
@@ -0,0 +1,242 @@
+//! MIDI note number helpers.
+use core::ops::{Div, MulAssign};
+
+use num_traits::{Float, Pow};
+
+use crate::{FreqGenerator, A4};
+
+/// MIDI note number of A4.
+const A4_NOTE: u8 = 69;
+
+impl<T> FreqGenerator<T>
+where
+    T: From<u8> + From<f32> + Pow<T, Output = T> + MulAssign + Div<Output = T> + Copy,
+{
+    /// Make a generator whose first [`next`](Iterator::next) yields the frequency of the given MIDI note number
+    /// (69 is A4 = 440 Hz). With a `scale` other than 12, each MIDI note is one step of that TET away from A4.
+    pub fn from_midi(note: u8, scale: T) -> Self {
+        // the generator starts one step below the note it yields first
+        let two: T = 2.into();
+        let a4: T = A4.into();
+        let freq = if note > A4_NOTE {
+            let mut freq = a4;
+            freq *= two.pow(<u8 as Into<T>>::into(note - A4_NOTE - 1) / scale);
+            freq
+        } else {
+            a4 / two.pow(<u8 as Into<T>>::into(A4_NOTE + 1 - note) / scale)
+        };
+        Self::new(freq, scale)
+    }
+}
+
+impl<T> FreqGenerator<T>
+where
+    T: Float + From<f32>,
+{
+    /// Bend every future note like a MIDI pitch-bend wheel: `bend` is the signed 14-bit bend value, from -8192
+    /// (`range_semitones` down) through 0 (no bend) to 8191 (`range_semitones` up). Values beyond are clamped.
+    #[must_use]
+    pub fn pitch_bend(self, bend: i16, range_semitones: f32) -> Self {
+        let bend = f32::from(bend.clamp(-8192, 8191));
+        // both ends reach the whole range
+        let amount = if bend < 0. {
+            bend / 8192.
+        } else {
+            bend / 8191.
+        };
+        self.detune(amount * range_semitones * 100.)
+    }
+}
+
+/// 12-TET frequency of every MIDI note number, from A4 = 440 Hz, computed at compile time for lookup-based synths.
+pub static NOTE_FREQS: [f32; 128] = note_freqs();
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn note_freqs() -> [f32; 128] {
+    // 2^(1/12)
+    const SEMITONE: f64 = 1.059_463_094_359_295_3;
+    let mut semitones = [1.; 12];
+    let mut i = 1;
+    while i < 12 {
+        semitones[i] = semitones[i - 1] * SEMITONE;
+        i += 1;
+    }
+    let mut freqs = [0.; 128];
+    let mut note = 0;
+    while note < 128 {
+        let from_a4 = note as i32 - A4_NOTE as i32;
+        // octaves are exact powers of 2 and semitones within one are at most 11 multiplications away from an A
+        let mut freq = A4 as f64 * semitones[from_a4.rem_euclid(12) as usize];
+        let mut octaves = from_a4.div_euclid(12);
+        while octaves > 0 {
+            freq *= 2.;
+            octaves -= 1;
+        }
+        while octaves < 0 {
+            freq /= 2.;
+            octaves += 1;
+        }
+        freqs[note] = freq as f32;
+        note += 1;
+    }
+    freqs
+}
+
+/// (Possibly fractional) 12-TET MIDI note number of a frequency, the inverse of [`FreqGenerator::from_midi`].
+/// The fractional part tells how far off the closest note the frequency is (0.01 is a cent).
+#[must_use]
+pub fn freq_to_midi(freq: f32) -> f32 {
+    f32::from(A4_NOTE) + 12. * Float::log2(freq / A4)
+}
+
+/// Closest 12-TET MIDI note number of a frequency, clamped to `0..=127`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn freq_to_midi_rounded(freq: f32) -> u8 {
+    // NaN clamps to NaN, which `as` then saturates to 0
+    Float::round(freq_to_midi(freq)).clamp(0., 127.) as u8
+}
+
+/// Size of a MIDI Tuning Standard bulk dump, see [`to_mts_bulk`].
+pub const MTS_BULK_LEN: usize = 408;
+
+/// Encode up to 128 note frequencies (from MIDI note 0 upward) as a MIDI Tuning Standard bulk tuning dump sysex message.
+///
+/// `device` is the sysex device ID (0x7F to address every device), `program` the tuning program number and `name` the
+/// tuning name, truncated or padded with spaces to 16 ASCII characters. Each frequency is encoded as 3 bytes: a semitone
+/// and a 14-bit fraction of it. Frequencies are clamped to the MTS range (8.1758 Hz to about 13289.7 Hz) and notes
+/// the iterator doesn't reach are marked as unchanged.
+#[must_use]
+pub fn to_mts_bulk(
+    frequencies: impl IntoIterator<Item = f32>,
+    device: u8,
+    program: u8,
+    name: &str,
+) -> [u8; MTS_BULK_LEN] {
+    let mut dump = [0x7F; MTS_BULK_LEN];
+    dump[..6].copy_from_slice(&[0xF0, 0x7E, device & 0x7F, 0x08, 0x01, program & 0x7F]);
+    let mut name = name.bytes().map(|c| if c.is_ascii() { c } else { b'?' });
+    for byte in &mut dump[6..22] {
+        *byte = name.next().unwrap_or(b' ');
+    }
+    for (note, freq) in dump[22..406].chunks_exact_mut(3).zip(frequencies) {
+        note.copy_from_slice(&mts_note(freq));
+    }
+    dump[406] = dump[1..406]
+        .iter()
+        .fold(0, |checksum, byte| checksum ^ byte)
+        & 0x7F;
+    dump[407] = 0xF7;
+    dump
+}
+
+/// 3-byte MTS frequency: semitone, then the fraction of a semitone in 14 bits.
+/// The top of the range stops one fraction short, as `[127, 0x7F, 0x7F]` means "no change".
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn mts_note(freq: f32) -> [u8; 3] {
+    let note = freq_to_midi(freq).clamp(0., 127. + 16382. / 16384.);
+    let note = if note.is_nan() { 0. } else { note };
+    let semitone = Float::floor(note);
+    let fraction = Float::round((note - semitone) * 16384.) as u16;
+    let (semitone, fraction) = match (semitone as u8, fraction) {
+        (semitone, 16384) => (semitone + 1, 0),
+        pair => pair,
+    };
+    [semitone, (fraction >> 7) as u8, (fraction & 0x7F) as u8]
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{freq_to_midi, freq_to_midi_rounded, to_mts_bulk, MTS_BULK_LEN, NOTE_FREQS};
+    use crate::FreqGenerator;
+
+    #[test]
+    fn a4() {
+        assert_eq!(
+            440f32,
+            FreqGenerator::from_midi(69, 12f32).next().unwrap().round()
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            8f32,
+            FreqGenerator::from_midi(0, 12f32).next().unwrap().round()
+        );
+        assert_eq!(
+            12544f32,
+            FreqGenerator::from_midi(127, 12f32).next().unwrap().round()
+        );
+    }
+
+    #[test]
+    fn to_midi() {
+        assert_eq!(69., freq_to_midi(440.));
+        let bb = freq_to_midi(466.);
+        assert!(bb > 69.9 && bb < 70.);
+        assert_eq!(70, freq_to_midi_rounded(466.));
+    }
+
+    #[test]
+    fn to_midi_clamped() {
+        assert_eq!(0, freq_to_midi_rounded(1.));
+        assert_eq!(127, freq_to_midi_rounded(20_000.));
+    }
+
+    #[test]
+    fn mts_bulk() {
+        let dump = to_mts_bulk(
+            (0..128).map(|note| FreqGenerator::from_midi(note, 12f32).next().unwrap()),
+            0x7F,
+            3,
+            "equal",
+        );
+        assert_eq!(MTS_BULK_LEN, dump.len());
+        assert_eq!([0xF0, 0x7E, 0x7F, 0x08, 0x01, 3], dump[..6]);
+        assert_eq!(*b"equal           ", dump[6..22]);
+        assert_eq!([69, 0, 0], dump[22 + 69 * 3..][..3]);
+        assert_eq!(
+            dump[1..406]
+                .iter()
+                .fold(0, |checksum, byte| checksum ^ byte)
+                & 0x7F,
+            dump[406]
+        );
+        assert_eq!(0xF7, dump[407]);
+    }
+
+    #[test]
+    fn mts_clamp() {
+        let dump = to_mts_bulk([1f32, 20_000., 440.], 0, 0, "");
+        assert_eq!([0, 0, 0], dump[22..25]);
+        // one fraction below the reserved no-change triple
+        assert_eq!([127, 0x7F, 0x7E], dump[25..28]);
+        assert_eq!([69, 0, 0], dump[28..31]);
+        assert_eq!([0x7F; 3], dump[31..34]);
+    }
+
+    #[test]
+    fn pitch_bend() {
+        let a4 = FreqGenerator::from_midi(69, 12f32);
+        assert_eq!(a4.clone().next(), a4.clone().pitch_bend(0, 2.).next());
+        let up = a4.clone().pitch_bend(8191, 2.).next().unwrap();
+        assert!((up - FreqGenerator::from_midi(71, 12f32).next().unwrap()).abs() < 1e-3);
+        let down = a4.clone().pitch_bend(i16::MIN, 2.).next().unwrap();
+        assert!((down - FreqGenerator::from_midi(67, 12f32).next().unwrap()).abs() < 1e-3);
+        let half = a4.pitch_bend(4096, 12.).next().unwrap();
+        assert!((freq_to_midi(half) - 75.).abs() < 1e-2);
+    }
+
+    #[test]
+    fn note_freqs() {
+        assert_eq!(440., NOTE_FREQS[69]);
+        assert!((NOTE_FREQS[60] - 261.63).abs() < 1e-2);
+        assert_eq!(880., NOTE_FREQS[81]);
+        let mut fg = FreqGenerator::from_midi(0, 12f64).exact();
+        for freq in NOTE_FREQS {
+            assert!((f64::from(freq) / fg.next().unwrap() - 1.).abs() < 1e-7);
+        }
+    }
+}
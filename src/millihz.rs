@@ -0,0 +1,102 @@
+//! Integer frequency generation for targets without an FPU.
+use core::iter::FusedIterator;
+
+use num_traits::{cast, Float};
+
+/// One in the Q1.31 fixed-point format of the step ratios.
+const ONE: u64 = 1 << 31;
+
+/// Equal temperament generator yielding `u32` frequencies in millihertz, so A4 is `440_000`.
+/// Acts as an iterator yielding notes from low to high pitch like a [`FreqGenerator`](crate::FreqGenerator)
+/// dividing the octave, using integer math only once made.
+///
+/// The step ratio is precomputed as a Q1.31 fixed-point number and multiplied up within each octave.
+/// Every octave restarts from exactly twice the previous one, so rounding errors don't compound across octaves.
+/// The iterator ends when a note doesn't fit in a `u32` anymore, above 4.29 MHz.
+#[derive(Debug, Clone)]
+pub struct MilliHzGenerator {
+    /// Base of the current octave, in millihertz.
+    octave: u32,
+    /// Ratio between two consecutive notes in Q1.31.
+    ratio: u64,
+    /// Ratio between the current note and the base of the current octave in Q1.31.
+    current: u64,
+    scale: u8,
+    step: u8,
+}
+
+impl MilliHzGenerator {
+    /// Make a new generator starting (exclusively) at `freq` millihertz with `scale` notes per octave.
+    /// The ratio between two consecutive notes is computed once here, with the only float operation.
+    ///
+    /// # Panics
+    /// If `scale` is 0.
+    pub fn new(freq: u32, scale: u8) -> Self {
+        assert!(scale != 0, "zero notes per octave");
+        let ratio = Float::powf(2f64, f64::from(scale).recip())
+            * cast::<_, f64>(ONE).unwrap_or_else(f64::nan);
+        Self {
+            octave: freq,
+            ratio: cast(Float::round(ratio)).unwrap_or(ONE),
+            current: ONE,
+            scale,
+            step: 0,
+        }
+    }
+}
+
+impl Iterator for MilliHzGenerator {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step + 1 == self.scale {
+            self.octave = self.octave.checked_mul(2)?;
+            self.current = ONE;
+            self.step = 0;
+        } else {
+            // both factors are below 2 in Q1.31, so the product fits
+            self.current = (self.current * self.ratio + ONE / 2) >> 31;
+            self.step += 1;
+        }
+        ((u64::from(self.octave) * self.current + ONE / 2) >> 31)
+            .try_into()
+            .ok()
+    }
+}
+
+/// Once a note overflows, every following one does too.
+impl FusedIterator for MilliHzGenerator {}
+
+#[cfg(test)]
+mod tests {
+    use super::MilliHzGenerator;
+    use crate::{FreqGenerator, A0};
+
+    #[test]
+    fn twelve_tet() {
+        let reference = FreqGenerator::new(f64::from(A0), 12.).exact();
+        let millihz = MilliHzGenerator::new(27_500, 12);
+        for (freq, reference) in millihz.zip(reference).take(88) {
+            assert!(
+                (f64::from(freq) - reference * 1000.).abs() <= 1.,
+                "{freq} {reference}"
+            );
+        }
+        let mut millihz = MilliHzGenerator::new(27_500, 12);
+        assert_eq!(Some(440_000), millihz.nth(47));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut octaves = MilliHzGenerator::new(1 << 30, 1);
+        assert_eq!(Some(1 << 31), octaves.next());
+        assert_eq!(None, octaves.next());
+        assert_eq!(None, octaves.next());
+    }
+
+    #[test]
+    #[should_panic = "zero notes per octave"]
+    fn zero_scale() {
+        MilliHzGenerator::new(27_500, 0);
+    }
+}
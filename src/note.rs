@@ -0,0 +1,174 @@
+//! Named 12-TET notes.
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use num_traits::Float;
+
+use crate::{cents, freq_to_midi, Accidental, FreqGenerator, Key, Mode, A4};
+
+/// Spelling of each 12-TET pitch class from C, sharps only.
+const SPELLINGS: [(Mode, Accidental); 12] = [
+    (Mode::C, Accidental::Natural),
+    (Mode::C, Accidental::Sharp),
+    (Mode::D, Accidental::Natural),
+    (Mode::D, Accidental::Sharp),
+    (Mode::E, Accidental::Natural),
+    (Mode::F, Accidental::Natural),
+    (Mode::F, Accidental::Sharp),
+    (Mode::G, Accidental::Natural),
+    (Mode::G, Accidental::Sharp),
+    (Mode::A, Accidental::Natural),
+    (Mode::A, Accidental::Sharp),
+    (Mode::B, Accidental::Natural),
+];
+
+/// A frequency with its closest 12-TET note name and scientific octave number (A4 = 440 Hz, octaves start on C).
+///
+/// Naming only makes sense in 12-TET: any other frequency gets the name of the closest 12-TET note,
+/// but `freq` stays the raw frequency.
+#[derive(Debug, Clone, Copy)]
+pub struct Note {
+    /// Note letter, with [`Mode`] standing for the letters as in [`Key`].
+    pub pitch_class: Mode,
+    /// Sharp or natural, notes are spelled with sharps.
+    pub accidental: Accidental,
+    /// Scientific octave number, -1 for the lowest MIDI octave.
+    pub octave: i8,
+    /// Frequency in Hz.
+    pub freq: f32,
+}
+
+impl Note {
+    /// Name the closest 12-TET note of `freq`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_freq(freq: f32) -> Self {
+        // semitones above C-1, NaN saturates to 0
+        let semitones = Float::round(freq_to_midi(freq)) as i32;
+        let (pitch_class, accidental) = SPELLINGS[semitones.rem_euclid(12) as usize];
+        Self {
+            pitch_class,
+            accidental,
+            octave: (semitones.div_euclid(12) - 1).clamp(i8::MIN.into(), i8::MAX.into()) as i8,
+            freq,
+        }
+    }
+}
+
+/// Notes are equal when their frequencies are, whatever their spelling.
+impl PartialEq for Note {
+    fn eq(&self, other: &Self) -> bool {
+        self.freq == other.freq
+    }
+}
+
+/// Notes compare by frequency, so a NaN frequency can't be compared. See [`cmp_freq`](crate::cmp_freq) for a total order.
+impl PartialOrd for Note {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.freq.partial_cmp(&other.freq)
+    }
+}
+
+/// Prints the spelling and octave, like `A#4`.
+impl Display for Note {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            Key::with_accidental(self.pitch_class, self.accidental),
+            self.octave
+        )
+    }
+}
+
+/// Iterator adapter naming each frequency, see [`FreqGenerator::named`].
+#[derive(Debug, Clone)]
+pub struct Named<I> {
+    frequencies: I,
+}
+
+impl<I> Iterator for Named<I>
+where
+    I: Iterator<Item = f32>,
+{
+    type Item = Note;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.frequencies.next().map(Note::from_freq)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.frequencies.size_hint()
+    }
+}
+
+impl FreqGenerator<f32> {
+    /// Yield [`Note`]s instead of raw frequencies, see [`Note::from_freq`].
+    /// Only meaningful for 12-TET generators: with a [`quarter_tone`](FreqGenerator::quarter_tone) generator,
+    /// half-sharps are named after the closest semitone (and its frequency is left as is).
+    #[must_use]
+    pub fn named(self) -> Named<Self> {
+        Named { frequencies: self }
+    }
+}
+
+/// A frequency displayed like a tuner readout: the frequency in Hz, then the closest 12-TET note
+/// and how far the frequency is from it in whole cents, like `445.0 Hz (A4 +20¢)`.
+///
+/// The precision of the formatter applies to the frequency, 1 decimal by default: `{:.2}` prints `445.00 Hz (A4 +20¢)`.
+/// Frequencies with no note, zero, negative or not finite, are printed alone, like `0.0 Hz`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Pitch(pub f32);
+
+impl Display for Pitch {
+    #[allow(clippy::cast_possible_truncation)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(1);
+        if !(self.0.is_finite() && self.0 > 0.) {
+            return write!(f, "{:.*} Hz", precision, self.0);
+        }
+        let note = Note::from_freq(self.0);
+        let semitones = Float::round(freq_to_midi(self.0)) - 69.;
+        // within half a semitone of the note, so the cast can't truncate
+        let deviation = Float::round(cents(A4, self.0) - 100. * semitones) as i32;
+        write!(f, "{:.*} Hz ({note} {deviation:+}¢)", precision, self.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    use super::Pitch;
+    use crate::{FreqGenerator, A0};
+
+    #[test]
+    fn named() {
+        let mut notes = FreqGenerator::new(A0, 12.).named().skip(47);
+        let a4 = notes.next().unwrap();
+        assert_eq!("A4", a4.to_string());
+        assert_eq!(4, a4.octave);
+        assert_eq!(440., a4.freq.round());
+        assert_eq!("A#4", notes.next().unwrap().to_string());
+        assert_eq!("B4", notes.next().unwrap().to_string());
+        assert_eq!("C5", notes.next().unwrap().to_string());
+    }
+
+    #[test]
+    fn pitch() {
+        assert_eq!("440.0 Hz (A4 +0¢)", Pitch(440.).to_string());
+        assert_eq!("445.0 Hz (A4 +20¢)", Pitch(445.).to_string());
+        assert_eq!("435.00 Hz (A4 -20¢)", alloc::format!("{:.2}", Pitch(435.)));
+        assert_eq!("27 Hz (A0 -32¢)", alloc::format!("{:.0}", Pitch(27.)));
+        assert_eq!("452.9 Hz (A#4 -50¢)", Pitch(452.9).to_string());
+        assert_eq!("0.0 Hz", Pitch(0.).to_string());
+        assert_eq!("-440.0 Hz", Pitch(-440.).to_string());
+        assert_eq!("NaN Hz", Pitch(f32::NAN).to_string());
+        assert_eq!("inf Hz", Pitch(f32::INFINITY).to_string());
+        assert_eq!("-inf Hz", Pitch(f32::NEG_INFINITY).to_string());
+    }
+}
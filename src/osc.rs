@@ -0,0 +1,149 @@
+//! Direct synthesis of notes.
+use core::{f64::consts::TAU, iter::FusedIterator};
+
+use num_traits::Float;
+
+use crate::FreqGenerator;
+
+/// Sine oscillator. Acts as an iterator yielding successive samples in `-1.0..=1.0` of a sine wave at a frequency,
+/// starting at 0 with a rising slope.
+///
+/// The phase is kept in cycles with double precision and wrapped after each sample,
+/// so it doesn't drift noticeably even after hours of samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SineOsc {
+    /// Position in the current cycle, from 0 to 1.
+    phase: f64,
+    /// Cycles per sample.
+    increment: f64,
+}
+
+impl SineOsc {
+    /// Make a new oscillator at `freq` Hz, yielding `sample_rate` samples per second.
+    #[must_use]
+    pub fn new(freq: f32, sample_rate: f32) -> Self {
+        Self {
+            phase: 0.,
+            increment: Float::fract(f64::from(freq) / f64::from(sample_rate)),
+        }
+    }
+
+    /// Change the frequency of the oscillator without resetting its phase, so the wave stays continuous.
+    pub fn set_freq(&mut self, freq: f32, sample_rate: f32) {
+        self.increment = Float::fract(f64::from(freq) / f64::from(sample_rate));
+    }
+}
+
+impl Iterator for SineOsc {
+    type Item = f32;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = Float::sin(TAU * self.phase) as f32;
+        self.phase += self.increment;
+        if self.phase >= 1. {
+            self.phase -= 1.;
+        } else if self.phase < 0. {
+            self.phase += 1.;
+        }
+        Some(sample)
+    }
+}
+
+impl FusedIterator for SineOsc {}
+
+/// Iterator adapter modulating each frequency of a stream by a slow sine in cents, so a held note wobbles.
+///
+/// It's meant for per-sample frequency streams, like [`repeat`](core::iter::repeat)ing a note for as many samples
+/// as it lasts: the modulation advances by one sample per frequency.
+#[derive(Debug, Clone)]
+pub struct Vibrato<I> {
+    input: I,
+    lfo: SineOsc,
+    depth_cents: f32,
+}
+
+impl<I> Vibrato<I> {
+    /// Make a vibrato on the `input` frequencies, sampled at `sample_rate`,
+    /// going `depth_cents` above and below them `rate_hz` times per second.
+    pub fn new(input: I, rate_hz: f32, depth_cents: f32, sample_rate: f32) -> Self {
+        Self {
+            input,
+            lfo: SineOsc::new(rate_hz, sample_rate),
+            depth_cents,
+        }
+    }
+}
+
+impl FreqGenerator<f32> {
+    /// Modulate the notes by a vibrato, see [`Vibrato::new`]. The modulation advances by one sample per note,
+    /// so `sample_rate` is the number of notes per second.
+    #[must_use]
+    pub fn vibrato(self, rate_hz: f32, depth_cents: f32, sample_rate: f32) -> Vibrato<Self> {
+        Vibrato::new(self, rate_hz, depth_cents, sample_rate)
+    }
+}
+
+impl<I> Iterator for Vibrato<I>
+where
+    I: Iterator<Item = f32>,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let freq = self.input.next()?;
+        let cents = self.depth_cents * self.lfo.next()?;
+        Some(freq * Float::powf(2., cents / 1200.))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SineOsc, Vibrato};
+    use crate::{cents, FreqGenerator, A4};
+    use core::iter::repeat;
+
+    #[test]
+    fn quarter_cycles() {
+        let mut osc = SineOsc::new(1., 4.);
+        for expected in [0., 1., 0., -1., 0., 1.] {
+            assert!((osc.next().unwrap() - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn long_run() {
+        // exactly 100 samples per cycle, so a minute later the wave is back at 0
+        let mut osc = SineOsc::new(441., 44_100.);
+        osc.nth(44_100 * 60 - 1);
+        assert!((0. ..1.).contains(&osc.phase));
+        assert!(osc.next().unwrap().abs() < 1e-6);
+    }
+
+    #[test]
+    fn vibrato() {
+        let mut flat = Vibrato::new(repeat(A4), 5., 0., 44_100.);
+        assert!(flat
+            .by_ref()
+            .take(10_000)
+            .all(|freq| (freq - A4).abs() < 1e-9));
+        let (mut lowest, mut highest) = (0f32, 0f32);
+        for freq in Vibrato::new(repeat(A4), 5., 20., 1000.).take(1000) {
+            let deviation = cents(A4, freq);
+            lowest = lowest.min(deviation);
+            highest = highest.max(deviation);
+        }
+        assert!((-20.001..-19.9).contains(&lowest));
+        assert!((19.9..=20.001).contains(&highest));
+        assert_eq!(3, Vibrato::new([A4; 3].into_iter(), 5., 20., 1000.).count());
+        // a quarter of a cycle per note: up a semitone each time, pulled 0, 20, 0 and -20 cents away
+        let notes = FreqGenerator::new(A4, 12.).vibrato(250., 20., 1000.);
+        for (freq, expected) in notes.zip([100., 220., 300., 380.]) {
+            assert!((cents(A4, freq) - expected).abs() < 1e-2);
+        }
+    }
+}
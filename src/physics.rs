@@ -0,0 +1,69 @@
+//! Physical quantities of notes.
+use core::ops::Div;
+
+use crate::FreqGenerator;
+
+/// Speed of sound in dry air at 20 °C, in m/s.
+pub const SPEED_OF_SOUND: f32 = 343.;
+
+impl<T> FreqGenerator<T>
+where
+    Self: Iterator<Item = T>,
+    T: Div<Output = T> + Copy,
+{
+    /// Yield the wavelength of each note instead of its frequency, `speed_of_sound / f`.
+    /// The unit is the one of `speed_of_sound` per second: meters for m/s.
+    pub fn wavelengths(self, speed_of_sound: T) -> impl Iterator<Item = T> {
+        self.map(move |freq| speed_of_sound / freq)
+    }
+
+    /// Yield the period of each note in seconds instead of its frequency, `1 / f`.
+    pub fn periods(self) -> impl Iterator<Item = T>
+    where
+        T: From<u8>,
+    {
+        self.map(|freq| T::from(1) / freq)
+    }
+
+    /// Yield the (fractional) number of samples in a cycle of each note at `sample_rate`, `sample_rate / f`.
+    /// Its inverse is the wavetable read increment, in tables per sample.
+    ///
+    /// Notes at or above the Nyquist frequency (`sample_rate / 2`) get 2 samples per cycle or less
+    /// and alias when played back, stop the generator before with [`up_to`](Self::up_to).
+    pub fn sample_indices(self, sample_rate: T) -> impl Iterator<Item = T> {
+        self.map(move |freq| sample_rate / freq)
+    }
+
+    /// Same as [`wavelengths`](Self::wavelengths) in the air, at [`SPEED_OF_SOUND`], in meters.
+    pub fn wavelengths_in_air(self) -> impl Iterator<Item = T>
+    where
+        T: From<f32>,
+    {
+        self.wavelengths(SPEED_OF_SOUND.into())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use crate::FreqGenerator;
+
+    #[test]
+    fn wavelengths() {
+        let mut fg = FreqGenerator::inclusive(343f32, 12.).wavelengths(343.);
+        assert_eq!(1., fg.next().unwrap());
+        let mut octave = FreqGenerator::inclusive(343f32, 12.)
+            .wavelengths_in_air()
+            .skip(12);
+        assert!((octave.next().unwrap() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn periods() {
+        let mut fg = FreqGenerator::inclusive(1000f32, 12.).periods();
+        assert!((fg.next().unwrap() - 0.001).abs() < 1e-9);
+        let mut samples = FreqGenerator::inclusive(441f32, 12.).sample_indices(44_100.);
+        assert_eq!(100., samples.next().unwrap());
+        assert!((samples.nth(11).unwrap() - 50.).abs() < 1e-4);
+    }
+}
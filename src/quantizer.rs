@@ -0,0 +1,90 @@
+//! Pitch correction of raw frequency streams.
+use num_traits::Float;
+
+use crate::ScaleGenerator;
+
+/// Iterator adapter pulling each frequency of a raw stream toward the closest note of a [`ScaleGenerator`],
+/// in cents, above or below its tonic.
+///
+/// The closest note is computed directly from the logarithm of each frequency, so every sample costs the same
+/// however far it is from the tonic. Frequencies with no note (zero, negative or not finite) go through unchanged.
+#[derive(Debug, Clone)]
+pub struct Quantizer<I> {
+    input: I,
+    scale: ScaleGenerator<f32>,
+    strength: f32,
+}
+
+impl<I> Quantizer<I> {
+    /// Make a quantizer for the `input` frequencies snapping to the notes of `scale`.
+    ///
+    /// `strength` interpolates in cents between each raw frequency (0) and the closest note (1),
+    /// it's clamped to `0.0..=1.0`.
+    pub fn new(input: I, scale: ScaleGenerator<f32>, strength: f32) -> Self {
+        Self {
+            input,
+            scale,
+            strength: strength.clamp(0., 1.),
+        }
+    }
+}
+
+impl<I> Iterator for Quantizer<I>
+where
+    I: Iterator<Item = f32>,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let freq = self.input.next()?;
+        if !(freq.is_finite() && freq > 0.) {
+            return Some(freq);
+        }
+        Some(freq * Float::powf(self.scale.nearest(freq) / freq, self.strength))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::Quantizer;
+    use crate::{FreqGenerator, Mode, ScaleGenerator, A0};
+
+    #[test]
+    fn quantize() {
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::F);
+        let mut hard = Quantizer::new([445f32, 500.].into_iter(), scale.clone(), 1.);
+        assert_eq!(440f32, hard.next().unwrap().round());
+        assert_eq!(494f32, hard.next().unwrap().round());
+        assert_eq!(None, hard.next());
+        let mut soft = Quantizer::new([445f32].into_iter(), scale.clone(), 0.5);
+        assert_eq!(442f32, soft.next().unwrap().round());
+        let mut raw = Quantizer::new([445f32].into_iter(), scale, 0.);
+        assert_eq!(445f32, raw.next().unwrap());
+    }
+
+    #[test]
+    fn closed_form() {
+        let scale = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN);
+        // same as walking the scale, far up the keyboard too
+        let walked = FreqGenerator::new(A0 * 1.1, 12f32).exact().take(100);
+        for (freq, quantized) in walked
+            .clone()
+            .zip(Quantizer::new(walked, scale.clone(), 1.))
+        {
+            let snapped = scale.snap(freq);
+            assert!((quantized / snapped - 1.).abs() < 1e-5, "{freq}");
+        }
+        // below the tonic, A0 ionian goes on with G#-1 a semitone down
+        let below = Quantizer::new([A0 * 0.95].into_iter(), scale.clone(), 1.).next();
+        assert!((below.unwrap() / (A0 / 2f32.powf(1. / 12.)) - 1.).abs() < 1e-5);
+        let samples = [f32::NAN, f32::INFINITY, 0., -440.];
+        let mut passed = Quantizer::new(samples.into_iter(), scale, 1.);
+        assert!(passed.next().unwrap().is_nan());
+        assert_eq!(&samples[1..], &[(); 3].map(|()| passed.next().unwrap()));
+    }
+}
@@ -0,0 +1,108 @@
+//! Exact ratio arithmetic for just intonation.
+use core::iter::FusedIterator;
+
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+
+/// Just intonation generator over exact fractions, so a perfect fifth is exactly 3/2 however high it's stacked.
+/// Acts as an iterator yielding notes from low to high pitch like [`JustGenerator`](crate::JustGenerator).
+///
+/// Rationals have no fractional powers, so the generator only multiplies: each note is the base of the current period
+/// times a ratio from a table, and the table repeats a period higher once exhausted.
+/// The iterator ends when a note doesn't fit in `T` anymore.
+#[derive(Debug, Clone)]
+pub struct RatioGenerator<T = i64> {
+    /// Base of the current period.
+    base: Ratio<T>,
+    period: Ratio<T>,
+    ratios: &'static [(u16, u16)],
+    index: usize,
+}
+
+impl<T> RatioGenerator<T>
+where
+    T: Clone + Integer + From<u16>,
+{
+    /// Make a new generator from a base frequency and the `(numerator, denominator)` ratios of one octave,
+    /// from `(1, 1)` up to but excluding `(2, 1)` (see [`JUST_MAJOR`](crate::JUST_MAJOR)).
+    /// The first note yielded is `base` times the first ratio.
+    ///
+    /// # Panics
+    /// If `ratios` is empty, as the generator would have nothing to cycle through.
+    pub fn new(base: Ratio<T>, ratios: &'static [(u16, u16)]) -> Self {
+        Self::with_period(base, ratios, Ratio::from_integer(2.into()))
+    }
+
+    /// Same as [`new`](Self::new) with the table repeating every `period` instead of every octave.
+    /// `with_period(base, &[(1, 1)], Ratio::new(3, 2))` stacks perfect fifths.
+    ///
+    /// # Panics
+    /// If `ratios` is empty, as the generator would have nothing to cycle through.
+    pub fn with_period(base: Ratio<T>, ratios: &'static [(u16, u16)], period: Ratio<T>) -> Self {
+        assert!(!ratios.is_empty(), "empty ratio table");
+        Self {
+            base,
+            period,
+            ratios,
+            index: 0,
+        }
+    }
+}
+
+impl<T> Iterator for RatioGenerator<T>
+where
+    T: Clone + Integer + CheckedMul + From<u16>,
+{
+    type Item = Ratio<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.ratios.len() {
+            self.base = self.base.checked_mul(&self.period)?;
+            self.index = 0;
+        }
+        let (num, den) = self.ratios[self.index];
+        let ratio = Ratio::new(num.into(), den.into());
+        let freq = self.base.checked_mul(&ratio)?;
+        self.index += 1;
+        Some(freq)
+    }
+}
+
+/// Once a note overflows, every following one does too.
+impl<T> FusedIterator for RatioGenerator<T> where Self: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::Ratio;
+    use num_traits::One;
+
+    use super::RatioGenerator;
+    use crate::JUST_MAJOR;
+
+    #[test]
+    fn just_major() {
+        let mut just = RatioGenerator::new(Ratio::from_integer(440i64), &JUST_MAJOR);
+        assert_eq!(Some(Ratio::from_integer(660)), just.nth(4));
+        assert_eq!(Some(Ratio::from_integer(880)), just.nth(2));
+        assert_eq!(Some(Ratio::from_integer(990)), just.next());
+    }
+
+    #[test]
+    fn pythagorean_comma() {
+        let mut fifths = RatioGenerator::with_period(Ratio::one(), &[(1, 1)], Ratio::new(3i64, 2));
+        let twelfth = fifths.nth(12).unwrap();
+        assert_eq!(
+            Ratio::new(531_441, 524_288),
+            twelfth / Ratio::from_integer(128)
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let mut octaves = RatioGenerator::new(Ratio::from_integer(1i64 << 60), &[(1, 1)]);
+        assert_eq!(Some(Ratio::from_integer(1 << 62)), octaves.nth(2));
+        assert_eq!(None, octaves.next());
+        assert_eq!(None, octaves.next());
+    }
+}
@@ -0,0 +1,232 @@
+//! Scala `.scl` scale files.
+use alloc::{string::String, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter, Write},
+};
+
+use num_traits::Float;
+
+/// One degree of a [`Scale`], as written in a `.scl` file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScaleStep {
+    /// Interval in cents above the first note.
+    Cents(f32),
+    /// Ratio (numerator, denominator) to the first note.
+    Ratio(u32, u32),
+}
+
+impl ScaleStep {
+    /// Frequency ratio of the step to the first note.
+    #[must_use]
+    pub fn ratio(self) -> f32 {
+        match self {
+            Self::Cents(cents) => Float::powf(2., cents / 1200.),
+            #[allow(clippy::cast_precision_loss)]
+            Self::Ratio(num, den) => num as f32 / den as f32,
+        }
+    }
+}
+
+/// Prints the step the way `.scl` files do: cents always have a decimal point, ratios are `n/m`.
+impl Display for ScaleStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cents(cents) => write!(f, "{cents:.5}"),
+            Self::Ratio(num, den) => write!(f, "{num}/{den}"),
+        }
+    }
+}
+
+/// Error returned when parsing an invalid `.scl` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSclError {
+    /// The description or note count line is missing.
+    MissingHeader,
+    /// The note count isn't a number.
+    InvalidCount,
+    /// A pitch line is neither cents nor a ratio, or is one no frequency can come from:
+    /// negative or non-finite cents, a zero numerator or denominator.
+    InvalidPitch,
+    /// There are fewer pitch lines than the note count announces.
+    MissingPitches,
+}
+
+impl Display for ParseSclError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingHeader => "missing description or note count",
+            Self::InvalidCount => "invalid note count",
+            Self::InvalidPitch => "invalid pitch",
+            Self::MissingPitches => "fewer pitches than the note count",
+        })
+    }
+}
+
+impl Error for ParseSclError {}
+
+/// Scale read from or written to a Scala `.scl` file. The first note (1/1) is implicit and the last step is the period,
+/// usually the octave.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scale {
+    /// One-line description of the scale.
+    pub description: String,
+    /// Steps above the first note, in order.
+    pub steps: Vec<ScaleStep>,
+}
+
+impl Scale {
+    /// Parse the content of a `.scl` file. Lines starting with `!` are comments, the first other line is the description,
+    /// the second one the number of notes and each of the next ones a pitch: cents if it has a decimal point, a ratio otherwise
+    /// (`n/m` or a lone integer). Anything after the pitch on its line is ignored.
+    /// Pitches must be above zero: negative or non-finite cents and zero ratios are rejected.
+    ///
+    /// # Errors
+    /// If the text isn't a valid `.scl` file, see [`ParseSclError`].
+    pub fn from_scl(text: &str) -> Result<Self, ParseSclError> {
+        let mut lines = text.lines().filter(|line| !line.starts_with('!'));
+        let description = lines.next().ok_or(ParseSclError::MissingHeader)?.trim();
+        let count = lines
+            .next()
+            .ok_or(ParseSclError::MissingHeader)?
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| ParseSclError::InvalidCount)?;
+        let steps = lines
+            .take(count)
+            .map(|line| {
+                let pitch = line
+                    .split_whitespace()
+                    .next()
+                    .ok_or(ParseSclError::InvalidPitch)?;
+                if pitch.contains('.') {
+                    pitch
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|cents| cents.is_finite() && *cents >= 0.)
+                        .map(ScaleStep::Cents)
+                } else if let Some((num, den)) = pitch.split_once('/') {
+                    num.parse()
+                        .ok()
+                        .zip(den.parse().ok())
+                        .map(|(num, den)| ScaleStep::Ratio(num, den))
+                } else {
+                    pitch.parse().map(|num| ScaleStep::Ratio(num, 1)).ok()
+                }
+                .filter(|step| !matches!(step, ScaleStep::Ratio(0, _) | ScaleStep::Ratio(_, 0)))
+                .ok_or(ParseSclError::InvalidPitch)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if steps.len() < count {
+            return Err(ParseSclError::MissingPitches);
+        }
+        Ok(Self {
+            description: description.into(),
+            steps,
+        })
+    }
+
+    /// Write the scale as the content of a `.scl` file.
+    #[must_use]
+    pub fn to_scl(&self) -> String {
+        let mut scl = String::new();
+        // writing to a `String` can't fail
+        let _ = writeln!(scl, "{}\n {}\n!", self.description, self.steps.len());
+        for step in &self.steps {
+            let _ = writeln!(scl, " {step}");
+        }
+        scl
+    }
+
+    /// Iterate over the frequencies of the scale above `base`, repeating it period after period.
+    /// Like [`FreqGenerator`](crate::FreqGenerator), `base` itself isn't yielded.
+    ///
+    /// Yields nothing if the scale has no steps.
+    pub fn frequencies(&self, base: f32) -> impl Iterator<Item = f32> + '_ {
+        let period = self.steps.last().map_or(1., |step| step.ratio());
+        let mut octave = base;
+        self.steps.iter().enumerate().cycle().map(move |(i, step)| {
+            let freq = octave * step.ratio();
+            if i + 1 == self.steps.len() {
+                octave *= period;
+            }
+            freq
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseSclError, Scale, ScaleStep};
+    use crate::{cents, FreqGenerator, A0};
+
+    const TET12: &str = "! 12tet.scl
+!
+12 tone equal temperament
+ 12
+!
+ 100.0
+ 200.
+ 300.0 cents
+ 400.0
+ 500.0
+ 600.0
+ 700.0
+ 800.0
+ 900.0
+ 1000.0
+ 1100.0
+ 2/1
+";
+
+    #[test]
+    fn parse() {
+        let scale = Scale::from_scl(TET12).unwrap();
+        assert_eq!("12 tone equal temperament", scale.description);
+        assert_eq!(12, scale.steps.len());
+        assert_eq!(ScaleStep::Cents(200.), scale.steps[1]);
+        assert_eq!(ScaleStep::Ratio(2, 1), scale.steps[11]);
+        assert_eq!(
+            Err(ParseSclError::MissingPitches),
+            Scale::from_scl("short\n3\n100.0\n")
+        );
+        assert_eq!(
+            Err(ParseSclError::InvalidPitch),
+            Scale::from_scl("bad\n1\nfifth\n")
+        );
+        assert_eq!(
+            Err(ParseSclError::InvalidCount),
+            Scale::from_scl("bad\ntwelve\n")
+        );
+        // the last one overflows to infinity
+        for pitch in [
+            "1/0",
+            "0/1",
+            "0",
+            "-100.0",
+            "10000000000000000000000000000000000000000.0",
+        ] {
+            assert_eq!(
+                Err(ParseSclError::InvalidPitch),
+                Scale::from_scl(&alloc::format!("x\n1\n{pitch}\n")),
+                "{pitch}"
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let scale = Scale::from_scl(TET12).unwrap();
+        let written = Scale::from_scl(&scale.to_scl()).unwrap();
+        assert_eq!(scale, written);
+        for (scl, equal) in written
+            .frequencies(A0)
+            .zip(FreqGenerator::new(A0, 12f32))
+            .take(12 * 4)
+        {
+            assert!(cents(equal, scl).abs() < 1.);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Serde support, behind the `serde` feature. Modes are (de)serialized as their modern name and keys as their spelling,
+//! both round-tripping through their [`FromStr`] implementation.
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Key, Mode};
+
+impl Serialize for Mode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.modern_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Mode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor(PhantomData, "a mode name"))
+    }
+}
+
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor(PhantomData, "a key spelling"))
+    }
+}
+
+/// Visitor parsing a string with [`FromStr`].
+struct FromStrVisitor<T>(PhantomData<T>, &'static str);
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.1)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Accidental, Key, Mode};
+
+    #[test]
+    fn mode() {
+        assert_eq!("\"Dorian\"", serde_json::to_string(&Mode::B).unwrap());
+        assert!(matches!(serde_json::from_str("\"dorian\""), Ok(Mode::B)));
+        assert!(serde_json::from_str::<Mode>("\"doric\"").is_err());
+    }
+
+    #[test]
+    fn key() {
+        assert_eq!(
+            "\"Bbb\"",
+            serde_json::to_string(&Key::with_accidental(Mode::B, Accidental::DoubleFlat)).unwrap()
+        );
+        assert!(matches!(
+            serde_json::from_str("\"A#\""),
+            Ok(Key {
+                note: Mode::A,
+                accidental: Accidental::Sharp
+            })
+        ));
+        for spelling in ["\"C\"", "\"F##\"", "\"Eb\""] {
+            assert_eq!(
+                spelling,
+                serde_json::to_string(&serde_json::from_str::<Key>(spelling).unwrap()).unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scale() {
+        use crate::{Scale, ScaleStep};
+        let scale = Scale {
+            description: "fifths".into(),
+            steps: alloc::vec![ScaleStep::Cents(701.955), ScaleStep::Ratio(2, 1)],
+        };
+        assert_eq!(
+            scale,
+            serde_json::from_str(&serde_json::to_string(&scale).unwrap()).unwrap()
+        );
+    }
+}
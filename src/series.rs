@@ -0,0 +1,97 @@
+//! Harmonic series generators.
+use core::{
+    iter::FusedIterator,
+    ops::{Div, Mul},
+};
+
+use num_traits::FromPrimitive;
+
+/// Overtone series generator. Acts as an iterator yielding the integer multiples of a fundamental:
+/// `f, 2f, 3f, 4f...`
+#[derive(Debug, Clone)]
+pub struct HarmonicSeries<T = f32> {
+    fundamental: T,
+    harmonic: usize,
+}
+
+impl<T> HarmonicSeries<T> {
+    /// Make a new series whose first note is `fundamental` itself.
+    pub fn new(fundamental: T) -> Self {
+        Self {
+            fundamental,
+            harmonic: 0,
+        }
+    }
+}
+
+impl<T> Iterator for HarmonicSeries<T>
+where
+    T: Mul<Output = T> + FromPrimitive + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.harmonic = self.harmonic.checked_add(1)?;
+        Some(self.fundamental * T::from_usize(self.harmonic)?)
+    }
+}
+
+impl<T> FusedIterator for HarmonicSeries<T> where Self: Iterator {}
+
+/// Undertone series generator. Acts as an iterator yielding a fundamental divided by successive integers:
+/// `f, f/2, f/3, f/4...`
+#[derive(Debug, Clone)]
+pub struct SubharmonicSeries<T = f32> {
+    fundamental: T,
+    subharmonic: usize,
+}
+
+impl<T> SubharmonicSeries<T> {
+    /// Make a new series whose first note is `fundamental` itself.
+    pub fn new(fundamental: T) -> Self {
+        Self {
+            fundamental,
+            subharmonic: 0,
+        }
+    }
+}
+
+impl<T> Iterator for SubharmonicSeries<T>
+where
+    T: Div<Output = T> + FromPrimitive + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.subharmonic = self.subharmonic.checked_add(1)?;
+        Some(self.fundamental / T::from_usize(self.subharmonic)?)
+    }
+}
+
+impl<T> FusedIterator for SubharmonicSeries<T> where Self: Iterator {}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{HarmonicSeries, SubharmonicSeries};
+
+    #[test]
+    fn harmonics() {
+        assert_eq!(500f32, HarmonicSeries::new(100f32).take(5).last().unwrap());
+        let mut series = HarmonicSeries::new(55f64);
+        assert_eq!(
+            [55f64, 110., 165.],
+            core::array::from_fn(|_| series.next().unwrap())
+        );
+    }
+
+    #[test]
+    fn subharmonics() {
+        assert_eq!(200f32, SubharmonicSeries::new(800f32).nth(3).unwrap());
+        let mut series = SubharmonicSeries::new(60f64);
+        assert_eq!(
+            [60f64, 30., 20., 15.],
+            core::array::from_fn(|_| series.next().unwrap())
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Batch note generation.
+use crate::FreqGenerator;
+
+impl FreqGenerator<f32> {
+    /// Same as [`fill`](Self::fill), computing 8 notes at once with SIMD instructions when the `simd` feature is
+    /// enabled: the first 8 notes are computed from the base, then each one is multiplied by 8 steps.
+    /// Rounding errors compound 8 times slower than on the scalar path, so notes may differ from
+    /// [`fill`](Self::fill) by a relative error around 1e-6.
+    ///
+    /// Without the `simd` feature, this is [`fill`](Self::fill).
+    pub fn fill_simd(&mut self, buf: &mut [f32]) -> usize {
+        #[cfg(feature = "simd")]
+        {
+            lanes::fill(self, buf)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            self.fill(buf)
+        }
+    }
+}
+
+#[cfg(feature = "simd")]
+mod lanes {
+    use num_traits::{cast, Float};
+    use wide::f32x8;
+
+    use crate::FreqGenerator;
+
+    pub(super) fn fill(fg: &mut FreqGenerator<f32>, buf: &mut [f32]) -> usize {
+        let next = cast::<_, f32>(fg.step).unwrap_or(f32::NAN) + 1.;
+        let steps = f32x8::splat(next) + f32x8::from([0., 1., 2., 3., 4., 5., 6., 7.]);
+        // the first 8 notes are computed from the base, the next ones are 8 steps above the previous ones
+        let mut notes = f32x8::splat(fg.base) * f32x8::splat(fg.period).pow_f32x8(steps / fg.scale);
+        let ratio = f32x8::splat(Float::powf(fg.period, 8. / fg.scale));
+        for chunk in buf.chunks_mut(8) {
+            chunk.copy_from_slice(&notes.to_array()[..chunk.len()]);
+            notes *= ratio;
+        }
+        fg.step += buf.len().cast_signed();
+        if let Some(&last) = buf.last() {
+            fg.freq = last;
+        }
+        buf.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FreqGenerator, A0};
+
+    #[test]
+    fn fill_simd() {
+        let mut scalar = FreqGenerator::new(A0, 12f32);
+        let mut simd = scalar.clone();
+        let (mut expected, mut notes) = ([0f32; 100], [0f32; 100]);
+        assert_eq!(100, scalar.fill(&mut expected));
+        assert_eq!(100, simd.fill_simd(&mut notes));
+        for (expected, note) in expected.into_iter().zip(notes) {
+            assert!((note / expected - 1.).abs() < 1e-4);
+        }
+        // picks up where the batch stopped
+        let next = (scalar.next().unwrap(), simd.next().unwrap());
+        assert!((next.1 / next.0 - 1.).abs() < 1e-4);
+        assert_eq!(0, simd.fill_simd(&mut []));
+    }
+}
@@ -0,0 +1,86 @@
+//! Stretched piano tunings.
+use core::iter::FusedIterator;
+
+use num_traits::{cast, Float};
+
+use crate::{FreqGenerator, A4};
+
+/// [`FreqGenerator`] with stretched octaves, see [`FreqGenerator::stretched`].
+#[derive(Debug, Clone)]
+pub struct Stretched<T = f32> {
+    fg: FreqGenerator<T>,
+    amount: T,
+}
+
+impl<T> FreqGenerator<T>
+where
+    T: Float,
+{
+    /// Stretch the tuning like a real piano, whose octaves are slightly wider than 2:1 to match the inharmonicity
+    /// of its strings: notes below A4 get flatter and notes above get sharper.
+    ///
+    /// This approximates the Railsback curve with an offset of `amount * d * |d|` cents, `d` being the distance
+    /// from A4 in octaves. 0 is pure equal temperament, and around 2 puts A0 about 30 cents flat like a grand piano.
+    pub fn stretched(self, amount: T) -> Stretched<T> {
+        Stretched { fg: self, amount }
+    }
+}
+
+impl<T> Stretched<T>
+where
+    T: Float + From<f32>,
+{
+    fn stretch(&self, freq: T) -> T {
+        let octaves = (freq / A4.into()).log2();
+        let cents = self.amount * octaves * octaves.abs();
+        freq * cast::<_, T>(2)
+            .unwrap_or_else(T::nan)
+            .powf(cents / cast(1200).unwrap_or_else(T::nan))
+    }
+}
+
+impl<T> Iterator for Stretched<T>
+where
+    T: Float + From<f32>,
+    FreqGenerator<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let freq = self.fg.next()?;
+        Some(self.stretch(freq))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let freq = self.fg.nth(n)?;
+        Some(self.stretch(freq))
+    }
+}
+
+impl<T> FusedIterator for Stretched<T> where Self: Iterator {}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use crate::{cents, FreqGenerator, A0, A4};
+
+    #[test]
+    fn railsback() {
+        let pure = FreqGenerator::new(A0, 12f32).exact();
+        let mut stretched = pure.clone().stretched(2.);
+        let mut pure = pure;
+        let bottom: [(f32, f32); 12] =
+            core::array::from_fn(|_| (pure.next().unwrap(), stretched.next().unwrap()));
+        assert!(bottom.iter().all(|(pure, stretched)| stretched < pure));
+        let a4 = (pure.nth(35).unwrap(), stretched.nth(35).unwrap());
+        assert!(cents(a4.0, a4.1).abs() < 1e-3);
+        assert_eq!(A4, a4.0.round());
+        let top = (pure.nth(38).unwrap(), stretched.nth(38).unwrap());
+        assert!(cents(top.0, top.1) > 15.);
+        // A0 on a grand piano
+        let mut a0 = FreqGenerator::new(A0 / 2., 12f32).stretched(2.).skip(11);
+        assert_eq!(-32., cents(A0, a0.next().unwrap()).round());
+        let mut flat = FreqGenerator::new(A0, 12f32).stretched(0.);
+        assert_eq!(FreqGenerator::new(A0, 12f32).nth(70), flat.nth(70));
+    }
+}
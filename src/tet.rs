@@ -0,0 +1,87 @@
+//! Equal temperament specifications.
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::Div,
+};
+
+use num_traits::Pow;
+
+use crate::FreqGenerator;
+
+/// Error returned when parsing an invalid equal temperament specification, see [`parse_tet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTetError;
+
+impl Display for ParseTetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid TET specification")
+    }
+}
+
+impl Error for ParseTetError {}
+
+/// Suffixes of a TET specification, checked in order.
+const SUFFIXES: [&str; 3] = ["-tet", "tet", "edo"];
+
+/// Number of divisions of the octave in a case-insensitive equal temperament specification:
+/// `"19-TET"`, `"19TET"` and `"19edo"` all give 19.
+///
+/// # Errors
+/// If the specification doesn't end with one of these suffixes, if the prefix isn't a plain decimal number
+/// or if it asks for zero divisions.
+pub fn parse_tet(s: &str) -> Result<u16, ParseTetError> {
+    let divisions = SUFFIXES
+        .iter()
+        .find_map(|suffix| {
+            let split = s.len().checked_sub(suffix.len())?;
+            let (divisions, end) = (s.get(..split)?, s.get(split..)?);
+            end.eq_ignore_ascii_case(suffix).then_some(divisions)
+        })
+        .ok_or(ParseTetError)?;
+    if divisions.is_empty() || !divisions.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseTetError);
+    }
+    match divisions.parse() {
+        Ok(0) | Err(_) => Err(ParseTetError),
+        Ok(divisions) => Ok(divisions),
+    }
+}
+
+impl<T> FreqGenerator<T>
+where
+    T: From<u8> + From<u16> + Pow<T, Output = T> + Div<Output = T> + Copy,
+{
+    /// Make a new generator starting (exclusively) at `base` dividing the octave in `divisions` equal steps,
+    /// from [`parse_tet`] for example.
+    pub fn tet(base: T, divisions: u16) -> Self {
+        Self::new(base, divisions.into())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{parse_tet, ParseTetError};
+    use crate::{FreqGenerator, A0};
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(24), parse_tet("24edo"));
+        assert_eq!(Ok(19), parse_tet("19-TET"));
+        assert_eq!(Ok(12), parse_tet("12Tet"));
+        assert_eq!(Ok(31), parse_tet("31EDO"));
+        for garbage in [
+            "0edo", "edo", "-TET", "x12-TET", "+12edo", "12", "12-edo", "70000edo", "12é",
+        ] {
+            assert_eq!(Err(ParseTetError), parse_tet(garbage), "{garbage}");
+        }
+    }
+
+    #[test]
+    fn tet() {
+        let mut generator = FreqGenerator::<f32>::tet(A0, parse_tet("24edo").unwrap());
+        assert_eq!(A0 * 2., generator.clone().exact().nth(23).unwrap());
+        assert!((generator.nth(23).unwrap() - A0 * 2.).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,437 @@
+//! Tunings other than equal temperament.
+use core::{
+    iter::FusedIterator,
+    ops::{Div, Mul},
+};
+
+use num_traits::Float;
+
+use crate::{cents, FreqGenerator, ScaleGenerator, MAX_INTERVALS};
+
+/// A source of pitches addressed by scale degree, so code rendering notes can be written once for every tuning.
+/// The trait is object-safe: use `&dyn Tuning` to mix tunings at runtime.
+///
+/// It's implemented by [`FreqGenerator`], [`JustGenerator`], [`CentsGenerator`] and [`ScaleGenerator`].
+/// The temperaments like [`pythagorean`] are plain iterators: turn their notes into [`cents`] above the base
+/// to make a [`CentsGenerator`] out of them.
+pub trait Tuning {
+    /// Frequency of the given degree, 0 being the base of the tuning. Negative degrees go below the base.
+    fn frequency(&self, degree: i32) -> f32;
+}
+
+/// Degrees are computed with the power formula from the construction-time base, whatever the generator's current position.
+impl Tuning for FreqGenerator<f32> {
+    #[allow(clippy::cast_precision_loss)]
+    fn frequency(&self, degree: i32) -> f32 {
+        self.base * Float::powf(self.period, degree as f32 / self.scale)
+    }
+}
+
+/// Ratios of the just intonation major scale, for [`JustGenerator`].
+pub const JUST_MAJOR: [(u16, u16); 7] = [(1, 1), (9, 8), (5, 4), (4, 3), (3, 2), (5, 3), (15, 8)];
+
+/// Just intonation generator. Acts as an iterator yielding notes from low to high pitch.
+/// Each note is the base frequency times a small integer ratio from a table, and the table repeats an octave higher once exhausted.
+#[derive(Debug, Clone)]
+pub struct JustGenerator<T = f32> {
+    base: T,
+    /// Base of the current octave.
+    octave: T,
+    ratios: &'static [(u16, u16)],
+    index: usize,
+}
+
+impl<T> JustGenerator<T> {
+    /// Make a new generator from a base frequency and the `(numerator, denominator)` ratios of one octave,
+    /// from `(1, 1)` up to but excluding `(2, 1)` (see [`JUST_MAJOR`]). The first note yielded is `base` times the first ratio.
+    ///
+    /// # Panics
+    /// If `ratios` is empty, as the generator would have nothing to cycle through.
+    pub fn new(base: T, ratios: &'static [(u16, u16)]) -> Self
+    where
+        T: Copy,
+    {
+        assert!(!ratios.is_empty(), "empty ratio table");
+        Self {
+            base,
+            octave: base,
+            ratios,
+            index: 0,
+        }
+    }
+}
+
+impl<T> Iterator for JustGenerator<T>
+where
+    T: From<u16> + Mul<Output = T> + Div<Output = T> + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (num, den) = self.ratios[self.index];
+        let freq = self.octave * num.into() / den.into();
+        self.index += 1;
+        if self.index == self.ratios.len() {
+            self.index = 0;
+            self.octave = self.octave * 2.into();
+        }
+        Some(freq)
+    }
+}
+
+impl<T> FusedIterator for JustGenerator<T> where Self: Iterator {}
+
+/// Degrees index the ratio table, wrapping an octave up or down past its ends.
+impl Tuning for JustGenerator<f32> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn frequency(&self, degree: i32) -> f32 {
+        let len = self.ratios.len() as i32;
+        let (num, den) = self.ratios[degree.rem_euclid(len) as usize];
+        self.base * f32::from(num) / f32::from(den) * Float::powi(2., degree.div_euclid(len))
+    }
+}
+
+/// Generator of a scale given in cents, see [`ScaleGenerator::from_cents`].
+/// Acts as an iterator yielding `base * 2^(cents / 1200)` for each value, then the same an octave higher and so on.
+#[derive(Debug, Clone)]
+pub struct CentsGenerator {
+    base: f32,
+    /// Base of the current octave.
+    octave: f32,
+    /// Ratio of each degree to the base, computed once.
+    ratios: [f32; MAX_INTERVALS],
+    len: usize,
+    index: usize,
+}
+
+impl CentsGenerator {
+    /// Make a new generator from a base frequency and the cents of each degree above it within the octave.
+    /// The first note yielded is `base` moved by the first value, `base` itself when it's 0.
+    ///
+    /// The values are used as they are: they should be sorted and start at 0 below 1200, otherwise the notes
+    /// come out of order or overlap the next octave.
+    ///
+    /// # Panics
+    /// If `cents` is empty, as the generator would have nothing to cycle through, or longer than [`MAX_INTERVALS`].
+    #[must_use]
+    pub fn new(base: f32, cents: &[f32]) -> Self {
+        assert!(!cents.is_empty(), "empty cents table");
+        assert!(
+            cents.len() <= MAX_INTERVALS,
+            "cents table longer than MAX_INTERVALS"
+        );
+        let mut ratios = [1.; MAX_INTERVALS];
+        for (ratio, &cents) in ratios.iter_mut().zip(cents) {
+            *ratio = Float::powf(2., cents / 1200.);
+        }
+        Self {
+            base,
+            octave: base,
+            ratios,
+            len: cents.len(),
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for CentsGenerator {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let freq = self.octave * self.ratios[self.index];
+        self.index += 1;
+        if self.index == self.len {
+            self.index = 0;
+            self.octave *= 2.;
+        }
+        Some(freq)
+    }
+}
+
+impl FusedIterator for CentsGenerator {}
+
+/// Degrees index the cents table, wrapping an octave up or down past its ends.
+impl Tuning for CentsGenerator {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn frequency(&self, degree: i32) -> f32 {
+        let len = self.len as i32;
+        self.base
+            * self.ratios[degree.rem_euclid(len) as usize]
+            * Float::powi(2., degree.div_euclid(len))
+    }
+}
+
+/// Degrees are counted from the [`tonic`](ScaleGenerator::tonic) in the mode the generator was made with,
+/// wrapping a period up or down past the ends of the interval pattern, whatever the generator's current position.
+impl Tuning for ScaleGenerator<f32> {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_precision_loss
+    )]
+    fn frequency(&self, degree: i32) -> f32 {
+        let len = self.intervals.len() as i32;
+        let steps = self.steps_from(self.start, degree.rem_euclid(len) as usize) as f32
+            + degree.div_euclid(len) as f32 * self.octave_steps() as f32;
+        self.tonic() * Float::powf(self.fg.period, steps / self.fg.scale)
+    }
+}
+
+impl ScaleGenerator<f32, CentsGenerator> {
+    /// Make a generator of an arbitrary tuning from the cents of each degree above `base` within the octave:
+    /// `[0., 200., 400., 500., 700., 900., 1100.]` is a 12-TET major scale. Unlike the other constructors,
+    /// the first note yielded is `base` itself (for a first value of 0), see [`CentsGenerator::new`].
+    ///
+    /// Each note of the [`CentsGenerator`] is a degree, so [`scale_len`](Self::scale_len) is the number of values.
+    ///
+    /// # Panics
+    /// If `cents` is empty or longer than [`MAX_INTERVALS`].
+    #[must_use]
+    pub fn from_cents(base: f32, cents: &[f32]) -> Self {
+        let generator = CentsGenerator::new(base, cents);
+        Self::from_intervals(generator, &[1; MAX_INTERVALS][..cents.len()])
+    }
+}
+
+/// Twelve notes of the Pythagorean tuning in ascending order, starting at `base`.
+///
+/// The notes are derived by stacking eleven perfect fifths (3:2) above `base` and reducing each into one octave,
+/// so the major third is the sharp 81:64 rather than the equal-tempered 2^(4/12). A twelfth fifth would overshoot
+/// seven octaves by the Pythagorean comma (531441:524288, about 23.5 cents).
+pub fn pythagorean(base: f32) -> impl Iterator<Item = f32> {
+    let mut notes = [0f32; 12];
+    // 3^11 fits in an f32 mantissa so this stays exact
+    let mut ratio = 1f32;
+    for fifth in 0..12 {
+        notes[fifth * 7 % 12] = base * ratio;
+        ratio *= 1.5;
+        if ratio >= 2. {
+            ratio /= 2.;
+        }
+    }
+    notes.into_iter()
+}
+
+/// Twelve notes of the quarter-comma meantone temperament in ascending order, starting at `base`.
+///
+/// The notes are a chain of fifths from E♭ to G♯ (taking `base` as C), each narrowed by a quarter of the syntonic
+/// comma to 5^(1/4), about 696.6 cents, so that four fifths make a pure 5:4 major third. The fifth left between
+/// G♯ and E♭ closes the circle: it's the wolf fifth, around 737.6 cents.
+#[allow(clippy::cast_precision_loss)]
+pub fn meantone(base: f32) -> impl Iterator<Item = f32> {
+    let mut notes = [0f32; 12];
+    for fifths in -3i32..=8 {
+        let mut ratio = Float::powf(5f32, fifths as f32 / 4.);
+        while ratio >= 2. {
+            ratio /= 2.;
+        }
+        while ratio < 1. {
+            ratio *= 2.;
+        }
+        // rem_euclid is within 0..12
+        notes[(fifths * 7).rem_euclid(12).unsigned_abs() as usize] = base * ratio;
+    }
+    notes.into_iter()
+}
+
+/// Twelve notes of Werckmeister III well temperament in ascending order, starting at `base`.
+///
+/// Taking `base` as C, the fifths C–G, G–D, D–A and B–F♯ are each narrowed by a quarter of the Pythagorean comma,
+/// about 696.1 cents, and the other eight are pure 3:2. Every key is playable, but the keys close to C get purer
+/// thirds than the remote ones.
+pub fn werckmeister_iii(base: f32) -> impl Iterator<Item = f32> {
+    // 3:2 narrowed by the fourth root of the Pythagorean comma (531441:524288)
+    let tempered = 1.5 / Float::powf(531_441f32 / 524_288., 0.25);
+    let mut notes = [0f32; 12];
+    let mut ratio = 1f32;
+    // fifths from C: C, G, D, A, E, B, F#...
+    for fifth in 0..12 {
+        notes[fifth * 7 % 12] = base * ratio;
+        ratio *= if matches!(fifth, 0..=2 | 5) {
+            tempered
+        } else {
+            1.5
+        };
+        if ratio >= 2. {
+            ratio /= 2.;
+        }
+    }
+    notes.into_iter()
+}
+
+/// Distance in cents from each of the first `n` notes of `reference` to the matching note of `other`, see [`cents`].
+/// Comparing 12-TET to [`JUST_MAJOR`] gives how sharp or flat each tempered degree is.
+///
+/// Stops early when either tuning runs out of notes. Pass generators by reference with
+/// [`by_ref`](Iterator::by_ref) to keep using them afterwards.
+pub fn compare_tunings(
+    reference: impl IntoIterator<Item = f32>,
+    other: impl IntoIterator<Item = f32>,
+    n: usize,
+) -> impl Iterator<Item = f32> {
+    reference
+        .into_iter()
+        .zip(other)
+        .take(n)
+        .map(|(reference, other)| cents(reference, other))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{
+        compare_tunings, meantone, pythagorean, werckmeister_iii, CentsGenerator, JustGenerator,
+        Tuning, JUST_MAJOR,
+    };
+    use crate::{cents, FreqGenerator, Mode, ScaleGenerator, A0};
+
+    #[test]
+    fn just_major() {
+        let mut just = JustGenerator::new(440f32, &JUST_MAJOR);
+        assert_eq!(440. * 1.5, just.nth(4).unwrap());
+        assert_eq!(880f32, just.nth(2).unwrap());
+        assert_eq!(880. * 9. / 8., just.next().unwrap());
+    }
+
+    #[test]
+    #[should_panic = "empty ratio table"]
+    fn empty_ratios() {
+        JustGenerator::new(440f32, &[]);
+    }
+
+    #[test]
+    fn pythagorean_third() {
+        let notes: [f32; 12] = {
+            let mut notes = pythagorean(256.);
+            core::array::from_fn(|_| notes.next().unwrap())
+        };
+        assert!(notes.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(256. * 81. / 64., notes[4]);
+        assert_eq!(256. * 3. / 2., notes[7]);
+        assert!(notes[4] > 256. * 2f32.powf(4. / 12.));
+        // twelve of its fifths, brought back into the octave, overshoot the base by the comma
+        let fifth = f64::from(notes[7] / notes[0]);
+        let mut stacked = 1f64;
+        for _ in 0..12 {
+            stacked *= fifth;
+            if stacked >= 2. {
+                stacked /= 2.;
+            }
+        }
+        assert!((stacked - 531_441. / 524_288.).abs() < 1e-9);
+        // so the fifth left to close the circle, F up to C, is short by about 23.46 cents
+        let comma = cents(notes[0], notes[7]) - cents(notes[5], notes[0] * 2.);
+        assert!((comma - 23.46).abs() < 0.01);
+    }
+
+    #[test]
+    fn tunings() {
+        fn render(tuning: &dyn Tuning, degree: i32) -> f32 {
+            tuning.frequency(degree)
+        }
+        let equal = FreqGenerator::new(A0, 12f32);
+        let just = JustGenerator::new(A0, &JUST_MAJOR);
+        assert_eq!(A0, equal.frequency(0));
+        assert_eq!(A0 * 2., equal.frequency(12));
+        assert_eq!(440f32, equal.frequency(48));
+        assert_eq!(A0 * 1.5, render(&just, 4));
+        assert_eq!(A0 * 0.75, render(&just, -3));
+        assert_eq!(A0 * 4. * 5. / 4., just.frequency(16));
+        assert!((render(&equal, 7) - render(&just, 4)).abs() < 0.05);
+        let major = CentsGenerator::new(A0, &[0., 200., 400., 500., 700., 900., 1100.]);
+        assert_eq!(A0, render(&major, 0));
+        assert!((render(&major, 4) - equal.frequency(7)).abs() < 1e-4);
+        assert!((render(&major, -3) - equal.frequency(-5)).abs() < 1e-4);
+        let mut notes = pythagorean(256.);
+        let steps: [f32; 12] = core::array::from_fn(|_| cents(256., notes.next().unwrap()));
+        assert!((render(&CentsGenerator::new(256., &steps), 7) - 384.).abs() < 1e-3);
+        // a running dorian scale still counts its degrees from the tonic
+        let mut dorian = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::DORIAN);
+        dorian.nth(10);
+        for (degree, semitones) in [(0, 0), (2, 3), (6, 10), (7, 12), (-1, -2), (-7, -12)] {
+            assert!((render(&dorian, degree) / equal.frequency(semitones) - 1.).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let equal = FreqGenerator::inclusive(A0, 12f32).exact();
+        assert!(compare_tunings(equal.clone(), equal.clone(), 88).all(|cents| cents == 0.));
+        assert_eq!(
+            88,
+            compare_tunings(equal.clone(), equal.clone(), 88).count()
+        );
+        // the tempered fifth is about 2 cents flat of the just one
+        let just = JustGenerator::new(A0, &JUST_MAJOR);
+        let major = [0, 2, 4, 5, 7, 9, 11].map(|semitones| equal.frequency(semitones));
+        let fifth = compare_tunings(just, major, 7).nth(4).unwrap();
+        assert!((fifth + 1.955).abs() < 1e-2);
+        // the Pythagorean notes run out first
+        assert_eq!(12, compare_tunings(pythagorean(A0), equal, 88).count());
+    }
+
+    #[test]
+    fn meantone_thirds() {
+        let notes: [f32; 12] = {
+            let mut notes = meantone(256.);
+            core::array::from_fn(|_| notes.next().unwrap())
+        };
+        assert!(notes.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(256., notes[0]);
+        assert!((notes[4] / notes[0] - 1.25).abs() < 1e-6);
+        assert!((notes[9] / notes[5] - 1.25).abs() < 1e-6);
+        assert!((cents(notes[0], notes[7]) - 696.58).abs() < 0.01);
+        // G# up to Eb
+        let wolf = cents(notes[8], notes[3] * 2.);
+        assert!((wolf - 737.64).abs() < 0.01);
+    }
+
+    #[test]
+    fn werckmeister() {
+        let notes: [f32; 12] = {
+            let mut notes = werckmeister_iii(256.);
+            core::array::from_fn(|_| notes.next().unwrap())
+        };
+        assert!(notes.windows(2).all(|w| w[0] < w[1]));
+        // published offsets from C
+        for (note, cents_from_c) in [
+            (2, 192.18),
+            (4, 390.225),
+            (6, 588.27),
+            (7, 696.09),
+            (9, 888.27),
+            (11, 1092.18),
+        ] {
+            assert!(
+                (cents(notes[0], notes[note]) - cents_from_c).abs() < 1.,
+                "{note}"
+            );
+        }
+        // tempered C-G and B-F#, pure E-B and F-C
+        assert!((cents(notes[0], notes[7]) - 696.09).abs() < 1.);
+        assert!((cents(notes[11] / 2., notes[6]) - 696.09).abs() < 1.);
+        assert!((cents(notes[4], notes[11]) - 701.955).abs() < 1.);
+        assert!((cents(notes[5], notes[0] * 2.) - 701.955).abs() < 1.);
+    }
+
+    #[test]
+    fn from_cents() {
+        let major = ScaleGenerator::from_cents(A0, &[0., 200., 400., 500., 700., 900., 1100.]);
+        assert_eq!(7, major.scale_len());
+        // the diatonic generator doesn't yield its tonic
+        let diatonic = ScaleGenerator::new(FreqGenerator::new(A0, 12f32), Mode::IONIAN);
+        for (freq, expected) in major.skip(1).zip(diatonic).take(29) {
+            assert!(cents(expected, freq).abs() < 1.);
+        }
+        let mut fifths = CentsGenerator::new(100., &[0., 702.]);
+        assert_eq!(Some(100.), fifths.next());
+        assert!((fifths.next().unwrap() - 150.).abs() < 0.01);
+        assert_eq!(Some(200.), fifths.next());
+    }
+
+    #[test]
+    #[should_panic = "empty cents table"]
+    fn empty_cents() {
+        let _ = ScaleGenerator::from_cents(A0, &[]);
+    }
+}
@@ -0,0 +1,168 @@
+//! Newtypes keeping frequencies and pitch distances apart.
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::Float;
+
+use crate::cents;
+
+/// A frequency in Hz.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hz(pub f32);
+
+/// A pitch distance in cents, 1200 per octave.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Cents(pub f32);
+
+impl Hz {
+    /// Distance from `reference` to this frequency, see [`cents`].
+    #[must_use]
+    pub fn to_cents_from(self, reference: Hz) -> Cents {
+        Cents(cents(reference.0, self.0))
+    }
+}
+
+impl Cents {
+    /// Distance of a frequency ratio: 1200 cents for 2.
+    #[must_use]
+    pub fn from_ratio(ratio: f32) -> Self {
+        Self(1200. * Float::log2(ratio))
+    }
+
+    /// Frequency ratio of this distance: 2 for 1200 cents.
+    #[must_use]
+    pub fn to_ratio(self) -> f32 {
+        Float::powf(2., self.0 / 1200.)
+    }
+}
+
+impl From<f32> for Hz {
+    fn from(freq: f32) -> Self {
+        Self(freq)
+    }
+}
+
+impl From<Hz> for f32 {
+    fn from(freq: Hz) -> Self {
+        freq.0
+    }
+}
+
+impl From<f32> for Cents {
+    fn from(cents: f32) -> Self {
+        Self(cents)
+    }
+}
+
+impl From<Cents> for f32 {
+    fn from(cents: Cents) -> Self {
+        cents.0
+    }
+}
+
+/// Scales a frequency by a ratio.
+impl Mul<f32> for Hz {
+    type Output = Hz;
+
+    fn mul(self, ratio: f32) -> Self::Output {
+        Hz(self.0 * ratio)
+    }
+}
+
+/// Divides a frequency by a ratio.
+impl Div<f32> for Hz {
+    type Output = Hz;
+
+    fn div(self, ratio: f32) -> Self::Output {
+        Hz(self.0 / ratio)
+    }
+}
+
+/// Ratio between two frequencies.
+impl Div for Hz {
+    type Output = f32;
+
+    fn div(self, other: Hz) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+/// Moves a frequency up by a distance.
+impl Add<Cents> for Hz {
+    type Output = Hz;
+
+    // adding a pitch distance multiplies the frequency
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, cents: Cents) -> Self::Output {
+        self * cents.to_ratio()
+    }
+}
+
+/// Moves a frequency down by a distance.
+impl Sub<Cents> for Hz {
+    type Output = Hz;
+
+    fn sub(self, cents: Cents) -> Self::Output {
+        self + -cents
+    }
+}
+
+impl Add for Cents {
+    type Output = Cents;
+
+    fn add(self, other: Cents) -> Self::Output {
+        Cents(self.0 + other.0)
+    }
+}
+
+impl Sub for Cents {
+    type Output = Cents;
+
+    fn sub(self, other: Cents) -> Self::Output {
+        Cents(self.0 - other.0)
+    }
+}
+
+impl Neg for Cents {
+    type Output = Cents;
+
+    fn neg(self) -> Self::Output {
+        Cents(-self.0)
+    }
+}
+
+impl Mul<f32> for Cents {
+    type Output = Cents;
+
+    fn mul(self, factor: f32) -> Self::Output {
+        Cents(self.0 * factor)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{Cents, Hz};
+    use crate::{FreqGenerator, A4};
+
+    #[test]
+    fn octave() {
+        let octave = Hz(880.).to_cents_from(Hz(A4));
+        assert_eq!(Cents(1200.), octave);
+        assert_eq!(2., octave.to_ratio());
+        assert_eq!(Cents(1200.), Cents::from_ratio(2.));
+        assert_eq!(Hz(880.), Hz(A4) + octave);
+        assert_eq!(Hz(220.), Hz(A4) - octave);
+        assert_eq!(2., Hz(880.) / Hz(A4));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Cents(700.), Cents(1200.) - Cents(500.));
+        assert_eq!(Cents(1900.), Cents(1200.) + Cents(700.));
+        assert_eq!(Cents(-600.), -Cents(1200.) * 0.5);
+        assert_eq!(Hz(660.), Hz(A4) * 1.5);
+        assert_eq!(Hz(220.), Hz(A4) / 2.);
+        let mut hz = FreqGenerator::new(A4, 12.).map(Hz);
+        assert!((hz.nth(11).unwrap() / Hz(880.) - 1.).abs() < 1e-6);
+    }
+}